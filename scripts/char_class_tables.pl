@@ -0,0 +1,65 @@
+#!/usr/bin/env perl
+# Generates src/linear_str_group/char_class_tables.rs from the Alphabetic, White_Space
+# and General_Category properties of the Unicode Character Database shipped with perl.
+#
+#     perl scripts/char_class_tables.pl > src/linear_str_group/char_class_tables.rs
+
+use strict;
+use warnings;
+use Unicode::UCD qw(prop_invlist prop_invmap);
+
+my ($starts, $categories, $format, $default) = prop_invmap("General_Category");
+die "unexpected format $format" unless $format eq "s";
+
+my @classes = ("Other") x 0x110000;
+
+# the classes are assigned from the lowest to the highest precedence
+for my $i (0 .. $#$starts - 1) {
+    my $category = $categories->[$i];
+    my $class = $category =~ /^P/ ? "Punctuation"
+              : $category =~ /^S/ ? "Symbol"
+              : $category eq "Cc" ? "Control"
+              : $category =~ /^N/ ? "Numeric"
+              : next;
+    @classes[$starts->[$i] .. $starts->[$i + 1] - 1] = ($class) x ($starts->[$i + 1] - $starts->[$i]);
+}
+
+sub assign {
+    my ($property, $class) = @_;
+    my @list = prop_invlist($property);
+    push @list, 0x110000 if @list % 2;
+    for (my $i = 0; $i < @list; $i += 2) {
+        for my $c ($list[$i] .. $list[$i + 1] - 1) {
+            # a numeric char is only overridden by an alphabetic one
+            next if $class eq "Whitespace" && $classes[$c] eq "Numeric";
+            $classes[$c] = $class;
+        }
+    }
+}
+
+assign("White_Space", "Whitespace");
+assign("Alphabetic", "Alphabetic");
+
+# adjacent chars of the same class are merged, the other chars are not stored
+my @ranges;
+for my $c (0 .. $#classes) {
+    my $class = $classes[$c];
+    next if $class eq "Other";
+    if (@ranges && $ranges[-1][1] + 1 == $c && $ranges[-1][2] eq $class) {
+        $ranges[-1][1] = $c;
+    } else {
+        push @ranges, [$c, $c, $class];
+    }
+}
+
+my $version = Unicode::UCD::UnicodeVersion();
+
+print "// Generated by scripts/char_class_tables.pl from the Unicode $version Alphabetic,\n";
+print "// White_Space and General_Category properties, do not edit.\n\n";
+
+print "use super::CharClass;\n\n";
+
+print "/// The ranges of `char`s of every class but `Other`.\n";
+print "pub(crate) static CHAR_CLASS_TABLE: &[(u32, u32, CharClass)] = &[\n";
+printf "    (0x%04X, 0x%04X, CharClass::%s),\n", @$_ for @ranges;
+print "];\n";
@@ -0,0 +1,52 @@
+#!/usr/bin/env perl
+# Generates src/script_runs/tables.rs from the Script property
+# of the Unicode Character Database shipped with perl.
+#
+#     perl scripts/script_tables.pl > src/script_runs/tables.rs
+
+use strict;
+use warnings;
+use Unicode::UCD qw(prop_invmap);
+
+my ($starts, $names, $format, $default) = prop_invmap("Script");
+die "unexpected format $format" unless $format eq "s";
+
+my %scripts = ($default => 1);
+my @ranges;
+for my $i (0 .. $#$starts - 1) {
+    my $name = $names->[$i];
+    next if $name eq $default;
+    $scripts{$name} = 1;
+    push @ranges, [$starts->[$i], $starts->[$i + 1] - 1, $name];
+}
+
+sub variant { my $name = shift; $name =~ s/_//g; $name }
+
+my $version = Unicode::UCD::UnicodeVersion();
+
+print "// Generated by scripts/script_tables.pl from the Unicode $version Script property, do not edit.\n\n";
+
+print "/// A Unicode script, as defined by the Script property of the Unicode $version\n";
+print "/// Character Database.\n";
+print "///\n";
+print "/// New scripts are added by new versions of Unicode, this enum is therefore non exhaustive.\n";
+print "#[non_exhaustive]\n";
+print "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]\n";
+print "pub enum Script {\n";
+print "    ", variant($_), ",\n" for sort keys %scripts;
+print "}\n\n";
+
+print "impl Script {\n";
+print "    /// Returns the Unicode long name of the script.\n";
+print "    pub fn name(self) -> &'static str {\n";
+print "        match self {\n";
+print "            Script::", variant($_), " => \"$_\",\n" for sort keys %scripts;
+print "        }\n";
+print "    }\n";
+print "}\n\n";
+
+print "pub(crate) const DEFAULT_SCRIPT: Script = Script::", variant($default), ";\n\n";
+
+print "pub(crate) static SCRIPT_TABLE: &[(u32, u32, Script)] = &[\n";
+printf "    (0x%04X, 0x%04X, Script::%s),\n", $_->[0], $_->[1], variant($_->[2]) for @ranges;
+print "];\n";
@@ -77,6 +77,10 @@ macro_rules! binary_group_by {
         impl<'a, T: 'a, P> std::iter::FusedIterator for $name<'a, T, P>
         where P: FnMut(&T, &T) -> bool,
         { }
+
+        unsafe impl<'a, T: 'a, P> crate::GroupIterator for $name<'a, T, P>
+        where P: FnMut(&T, &T) -> bool,
+        { }
     }
 }
 
@@ -0,0 +1,192 @@
+use std::cmp::Ordering::{Less, Greater};
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::fmt;
+use crate::exponential_search_by;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive integer that can be used as the key of fixed-width buckets.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait BucketKey: Copy + Ord + private::Sealed {
+    /// Returns `true` if the integer is strictly positive.
+    fn is_positive(self) -> bool;
+
+    /// Returns the start of the bucket of `width` aligned on `origin` that contains `self`,
+    /// or `None` if this start is lower than the minimum of the type.
+    fn bucket_start(self, origin: Self, width: Self) -> Option<Self>;
+
+    /// Returns `self + width`, or `None` on overflow.
+    fn checked_add(self, width: Self) -> Option<Self>;
+}
+
+macro_rules! bucket_key {
+    ($($int:ty: $uint:ty),*) => {
+        $(
+            impl private::Sealed for $int {}
+
+            impl BucketKey for $int {
+                #[inline]
+                fn is_positive(self) -> bool {
+                    self > 0
+                }
+
+                #[inline]
+                fn bucket_start(self, origin: Self, width: Self) -> Option<Self> {
+                    // the distance to the origin is computed in the unsigned type
+                    // as it can be greater than the maximum of a signed type
+                    let width = width as $uint;
+                    if self >= origin {
+                        let rem = (self.wrapping_sub(origin) as $uint) % width;
+                        Some(self.wrapping_sub(rem as $int))
+                    } else {
+                        let rem = (origin.wrapping_sub(self) as $uint) % width;
+                        if rem == 0 { return Some(self) }
+                        self.checked_sub((width - rem) as $int)
+                    }
+                }
+
+                #[inline]
+                fn checked_add(self, width: Self) -> Option<Self> {
+                    <$int>::checked_add(self, width)
+                }
+            }
+        )*
+    }
+}
+
+bucket_key!{
+    u8: u8, u16: u16, u32: u32, u64: u64, u128: u128, usize: usize,
+    i8: u8, i16: u16, i32: u32, i64: u64, i128: u128, isize: usize
+}
+
+fn first_bucket_start<K: BucketKey>(key: K, origin: K, width: K) -> K {
+    match key.bucket_start(origin, width) {
+        Some(start) => start,
+        None => panic!("the first bucket starts below the minimum of the key type"),
+    }
+}
+
+/// An iterator that will return the fixed-width buckets of a slice sorted by key,
+/// along with the start of every bucket.
+///
+/// The buckets without any element are returned with an empty slice
+/// and the end of a bucket is found using an exponential search.
+pub struct BucketGroupByKey<'a, T: 'a, F, K> {
+    slice: &'a [T],
+    func: F,
+    width: K,
+    next: Option<K>,
+    end: Option<K>,
+}
+
+impl<'a, T: 'a, F, K> BucketGroupByKey<'a, T, F, K>
+where F: FnMut(&T) -> K,
+      K: BucketKey,
+{
+    /// Creates an iterator over the buckets between the first and the last element.
+    ///
+    /// The keys lower than `origin` are in the buckets aligned on `origin` below it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not strictly positive or if the start of the bucket
+    /// of the first element is lower than the minimum of the key type.
+    pub fn new(slice: &'a [T], mut func: F, origin: K, width: K) -> Self {
+        assert!(width.is_positive(), "width must be strictly positive");
+        let next = slice.first().map(|x| first_bucket_start(func(x), origin, width));
+        BucketGroupByKey { slice, func, width, next, end: None }
+    }
+
+    /// Creates an iterator over the buckets that overlap the given range of keys,
+    /// the elements outside of this range are ignored.
+    ///
+    /// The keys lower than `origin` are in the buckets aligned on `origin` below it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not strictly positive or if the start of the bucket
+    /// of the range start is lower than the minimum of the key type.
+    pub fn with_range(slice: &'a [T], mut func: F, origin: K, width: K, range: Range<K>) -> Self {
+        assert!(width.is_positive(), "width must be strictly positive");
+
+        let start = slice.partition_point(|x| func(x) < range.start);
+        let end = start + slice[start..].partition_point(|x| func(x) < range.end);
+        let slice = &slice[start..end];
+
+        let next = if range.start < range.end { Some(first_bucket_start(range.start, origin, width)) } else { None };
+        BucketGroupByKey { slice, func, width, next, end: Some(range.end) }
+    }
+}
+
+impl<'a, T: 'a, F, K> BucketGroupByKey<'a, T, F, K> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<'a, T: 'a, F, K> Iterator for BucketGroupByKey<'a, T, F, K>
+where F: FnMut(&T) -> K,
+      K: BucketKey,
+{
+    type Item = (K, &'a [T]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next?;
+
+        let finished = match self.end {
+            Some(end) => start >= end,
+            None => self.slice.is_empty(),
+        };
+        if finished {
+            self.next = None;
+            return None
+        }
+
+        let bucket_end = start.checked_add(self.width);
+        let len = match bucket_end {
+            Some(bucket_end) => {
+                let func = &mut self.func;
+                let predicate = |x: &T| if func(x) < bucket_end { Less } else { Greater };
+                exponential_search_by(self.slice, predicate).unwrap_err()
+            },
+            None => self.slice.len(),
+        };
+
+        let (head, tail) = self.slice.split_at(len);
+        self.slice = tail;
+        self.next = bucket_end;
+
+        Some((start, head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) if !self.slice.is_empty() => (1, None),
+            Some(_) if self.end.is_some() => (0, None),
+            _ => (0, Some(0)),
+        }
+    }
+}
+
+impl<'a, T: 'a, F, K> FusedIterator for BucketGroupByKey<'a, T, F, K>
+where F: FnMut(&T) -> K,
+      K: BucketKey,
+{ }
+
+impl<'a, T: 'a + fmt::Debug, F, K: fmt::Debug> fmt::Debug for BucketGroupByKey<'a, T, F, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BucketGroupByKey")
+            .field("remainder", &self.slice)
+            .field("width", &self.width)
+            .field("next", &self.next)
+            .field("end", &self.end)
+            .finish()
+    }
+}
@@ -0,0 +1,95 @@
+mod bucket_group_by_key;
+
+pub use self::bucket_group_by_key::{BucketGroupByKey, BucketKey};
+
+#[cfg(test)]
+mod tests {
+    use crate::GroupBy;
+
+    #[test]
+    fn empty_buckets() {
+        let slice = &[3, 4, 12, 31, 32];
+
+        let buckets: Vec<_> = slice.bucket_group_by_key(|x| *x, 0, 10).collect();
+        assert_eq!(buckets, vec![
+            (0, &[3, 4][..]),
+            (10, &[12][..]),
+            (20, &[][..]),
+            (30, &[31, 32][..]),
+        ]);
+    }
+
+    #[test]
+    fn negative_origin() {
+        let slice = &[-7i64, -5, -1, 2];
+
+        let buckets: Vec<_> = slice.bucket_group_by_key(|x| *x, 1, 4).collect();
+        assert_eq!(buckets, vec![
+            (-7, &[-7, -5][..]),
+            (-3, &[-1][..]),
+            (1, &[2][..]),
+        ]);
+    }
+
+    #[test]
+    fn range_limited() {
+        let slice = &[(5u32, 'a'), (15, 'b'), (16, 'c'), (40, 'd'), (55, 'e')];
+
+        let buckets: Vec<_> = slice.bucket_group_by_key_in(|x| x.0, 0, 10, 10..50)
+            .map(|(start, group)| (start, group.len()))
+            .collect();
+        assert_eq!(buckets, vec![(10, 2), (20, 0), (30, 0), (40, 1)]);
+
+        let buckets: Vec<_> = slice.bucket_group_by_key_in(|x| x.0, 0, 10, 60..80)
+            .map(|(start, group)| (start, group.len()))
+            .collect();
+        assert_eq!(buckets, vec![(60, 0), (70, 0)]);
+
+        assert_eq!(slice.bucket_group_by_key_in(|x| x.0, 0, 10, 30..30).next(), None);
+    }
+
+    #[test]
+    fn overflowing_bucket() {
+        let slice = &[250u8, 255];
+
+        let buckets: Vec<_> = slice.bucket_group_by_key(|x| *x, 0, 100).collect();
+        assert_eq!(buckets, vec![(200, &[250, 255][..])]);
+    }
+
+    #[test]
+    fn unsigned_keys_below_origin() {
+        let slice = &[3u32, 15];
+
+        let buckets: Vec<_> = slice.bucket_group_by_key(|x| *x, 10, 10).collect();
+        assert_eq!(buckets, vec![(0, &[3][..]), (10, &[15][..])]);
+
+        let buckets: Vec<_> = slice.bucket_group_by_key(|x| *x, 14, 4).collect();
+        assert_eq!(buckets, vec![(2, &[3][..]), (6, &[][..]), (10, &[][..]), (14, &[15][..])]);
+
+        let buckets: Vec<_> = slice.bucket_group_by_key_in(|x| *x, 10, 5, 3..12)
+            .map(|(start, group)| (start, group.len()))
+            .collect();
+        assert_eq!(buckets, vec![(0, 1), (5, 0), (10, 0)]);
+    }
+
+    #[test]
+    fn signed_keys_far_from_origin() {
+        let slice = &[-128i8, 127];
+
+        let buckets: Vec<_> = slice.bucket_group_by_key(|x| *x, 72, 100).collect();
+        assert_eq!(buckets, vec![(-128, &[-128][..]), (-28, &[][..]), (72, &[127][..])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "below the minimum of the key type")]
+    fn bucket_below_minimum() {
+        let slice = &[3u32, 15];
+        let _ = slice.bucket_group_by_key(|x| *x, 10, 20).next();
+    }
+
+    #[test]
+    fn empty_slice() {
+        let slice: &[i32] = &[];
+        assert_eq!(slice.bucket_group_by_key(|x| *x, 0, 10).next(), None);
+    }
+}
@@ -0,0 +1,167 @@
+use std::io::{self, BufRead};
+use std::iter::FusedIterator;
+use std::fmt;
+use crate::linear_str_group::trim_newline;
+
+/// A convenient trait to construct an iterator returning groups of consecutive lines
+/// read from a [`BufRead`].
+///
+/// [`BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+pub trait BufReadGroupBy: BufRead + Sized {
+    /// Returns a streaming iterator on groups of consecutive lines that will use
+    /// the given function to generate keys and determine groups based on them.
+    ///
+    /// The function is given every line without its `"\n"` or `"\r\n"` line terminator,
+    /// the groups contain the line terminators of their lines.
+    ///
+    /// ```rust
+    /// use slice_group_by::BufReadGroupBy;
+    ///
+    /// let text = "a 1\r\na 2\nb 3\nb 4";
+    ///
+    /// let mut iter = text.as_bytes().lines_group_by_key(|line| line.split(' ').next().map(String::from));
+    ///
+    /// assert_eq!(iter.next().unwrap().unwrap(), "a 1\r\na 2\n");
+    /// assert_eq!(iter.next().unwrap().unwrap(), "b 3\nb 4");
+    /// assert!(iter.next().is_none());
+    /// ```
+    fn lines_group_by_key<F, K>(self, func: F) -> BufReadLinesGroupByKey<Self, F, K>
+    where F: FnMut(&str) -> K,
+          K: PartialEq;
+}
+
+impl<R: BufRead> BufReadGroupBy for R {
+    fn lines_group_by_key<F, K>(self, func: F) -> BufReadLinesGroupByKey<Self, F, K>
+    where F: FnMut(&str) -> K,
+          K: PartialEq,
+    {
+        BufReadLinesGroupByKey::new(self, func)
+    }
+}
+
+/// A streaming iterator that will return groups of consecutive lines read from a [`BufRead`].
+///
+/// It will give every line, without its `"\n"` or `"\r\n"` line terminator, to the given
+/// function, producing a key and comparing the keys to determine groups. The lines are
+/// read into a single internal buffer, a group can either be borrowed from this buffer
+/// with [`next_group`] or returned as an owned `String` by the `Iterator` implementation.
+///
+/// An error is returned when the reader fails, the group being read is kept, as well as
+/// the part of the line already read, and both are continued by the next call.
+/// An error of kind `InvalidData` is returned when a line is not valid UTF-8,
+/// this line is skipped and the group being read is continued by the next call.
+///
+/// [`BufRead`]: https://doc.rust-lang.org/std/io/trait.BufRead.html
+/// [`next_group`]: #method.next_group
+pub struct BufReadLinesGroupByKey<R, F, K> {
+    reader: R,
+    func: F,
+    buffer: Vec<u8>,
+    consumed: usize,
+    line_start: usize,
+    key: Option<K>,
+    done: bool,
+}
+
+impl<R, F, K> BufReadLinesGroupByKey<R, F, K>
+where R: BufRead,
+      F: FnMut(&str) -> K,
+      K: PartialEq,
+{
+    pub fn new(reader: R, func: F) -> Self {
+        BufReadLinesGroupByKey {
+            reader,
+            func,
+            buffer: Vec::new(),
+            consumed: 0,
+            line_start: 0,
+            key: None,
+            done: false,
+        }
+    }
+
+    /// Returns the next group of lines, borrowed from the internal buffer.
+    pub fn next_group(&mut self) -> Option<io::Result<&str>> {
+        // the buffer starts with the lines of the returned group, followed by the
+        // lines of the next group, if any, and by the part of the line being read
+        self.buffer.drain(..self.consumed);
+        self.line_start -= self.consumed;
+        self.consumed = 0;
+
+        if self.done { return None }
+
+        loop {
+            let start = self.line_start;
+            match self.reader.read_until(b'\n', &mut self.buffer) {
+                Ok(0) if self.buffer.len() == start => {
+                    self.done = true;
+                    self.consumed = start;
+                    return match self.key.take() {
+                        Some(_) => Some(Ok(self.group())),
+                        None => None,
+                    };
+                },
+                Ok(_) => {
+                    let line = match std::str::from_utf8(&self.buffer[start..]) {
+                        Ok(line) => line,
+                        Err(e) => {
+                            self.buffer.truncate(start);
+                            return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e)));
+                        },
+                    };
+
+                    let next_key = (self.func)(trim_newline(line));
+                    let new_group = match &self.key { Some(key) => *key != next_key, None => false };
+                    self.key = Some(next_key);
+                    self.line_start = self.buffer.len();
+
+                    if new_group {
+                        self.consumed = start;
+                        return Some(Ok(self.group()));
+                    }
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+
+    fn group(&self) -> &str {
+        // the lines before the consumed index have been validated
+        unsafe { std::str::from_utf8_unchecked(&self.buffer[..self.consumed]) }
+    }
+
+    /// Returns the underlying reader, the lines already read
+    /// and not yet returned are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R, F, K> Iterator for BufReadLinesGroupByKey<R, F, K>
+where R: BufRead,
+      F: FnMut(&str) -> K,
+      K: PartialEq,
+{
+    type Item = io::Result<String>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_group().map(|result| result.map(String::from))
+    }
+}
+
+impl<R, F, K> FusedIterator for BufReadLinesGroupByKey<R, F, K>
+where R: BufRead,
+      F: FnMut(&str) -> K,
+      K: PartialEq,
+{ }
+
+impl<R: fmt::Debug, F, K: fmt::Debug> fmt::Debug for BufReadLinesGroupByKey<R, F, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BufReadLinesGroupByKey")
+            .field("reader", &self.reader)
+            .field("buffer", &String::from_utf8_lossy(&self.buffer))
+            .field("key", &self.key)
+            .finish()
+    }
+}
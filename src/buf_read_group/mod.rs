@@ -0,0 +1,111 @@
+mod buf_read_group;
+
+pub use self::buf_read_group::{BufReadGroupBy, BufReadLinesGroupByKey};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{self, BufReader, Cursor, Read};
+
+    fn field(line: &str) -> String {
+        line.split(',').next().unwrap_or("").to_owned()
+    }
+
+    /// A reader that returns its chunks one by one, failing in place of every `None`.
+    struct FailingReader(Vec<Option<&'static [u8]>>);
+
+    impl Read for FailingReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() { return Ok(0) }
+            match self.0.remove(0) {
+                Some(chunk) => {
+                    buf[..chunk.len()].copy_from_slice(chunk);
+                    Ok(chunk.len())
+                },
+                None => Err(io::Error::new(io::ErrorKind::BrokenPipe, "failing reader")),
+            }
+        }
+    }
+
+    #[test]
+    fn groups_span_read_buffers() {
+        let text = "apple,1\napple,22\napricot,3\nbanana,4\nbanana,5\n";
+
+        // a tiny capacity makes every line span several read buffers
+        let reader = BufReader::with_capacity(3, text.as_bytes());
+        let groups: Vec<_> = reader.lines_group_by_key(field).map(Result::unwrap).collect();
+
+        assert_eq!(groups, vec!["apple,1\napple,22\n", "apricot,3\n", "banana,4\nbanana,5\n"]);
+    }
+
+    #[test]
+    fn crlf_and_missing_final_newline() {
+        let text = "a,1\r\na,2\nb,3\r\nb,4";
+
+        let mut iter = Cursor::new(text).lines_group_by_key(field);
+        assert_eq!(iter.next_group().unwrap().unwrap(), "a,1\r\na,2\n");
+        assert_eq!(iter.next_group().unwrap().unwrap(), "b,3\r\nb,4");
+        assert!(iter.next_group().is_none());
+        assert!(iter.next_group().is_none());
+
+        // the key is computed without the line terminator
+        let mut iter = Cursor::new("x\r\nx\n\nx").lines_group_by_key(|line| line.to_owned());
+        assert_eq!(iter.next().unwrap().unwrap(), "x\r\nx\n");
+        assert_eq!(iter.next().unwrap().unwrap(), "\n");
+        assert_eq!(iter.next().unwrap().unwrap(), "x");
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn empty_reader() {
+        let mut iter = Cursor::new("").lines_group_by_key(field);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn failing_reader() {
+        // the first line fails before its key is known
+        let reader = FailingReader(vec![Some(b"a,"), None, Some(b"1\na,2\nb,"), None, Some(b"3\nb,4")]);
+
+        let mut iter = BufReader::new(reader).lines_group_by_key(field);
+        assert_eq!(iter.next_group().unwrap().unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(iter.next_group().unwrap().unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+
+        // the partial lines are kept and keyed as a whole
+        assert_eq!(iter.next_group().unwrap().unwrap(), "a,1\na,2\n");
+        assert_eq!(iter.next_group().unwrap().unwrap(), "b,3\nb,4");
+        assert!(iter.next_group().is_none());
+
+        // the group fails in the middle of a line that starts a new group
+        let reader = FailingReader(vec![Some(b"a,1\nb"), None, Some(b"b,2\n")]);
+
+        let mut iter = BufReader::new(reader).lines_group_by_key(field);
+        assert!(iter.next().unwrap().is_err());
+        assert_eq!(iter.next().unwrap().unwrap(), "a,1\n");
+        assert_eq!(iter.next().unwrap().unwrap(), "bb,2\n");
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn invalid_utf8_first_line() {
+        let bytes: &[u8] = b"\xff,1\na,2\n";
+
+        let mut iter = bytes.lines_group_by_key(field);
+        assert_eq!(iter.next_group().unwrap().unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(iter.next_group().unwrap().unwrap(), "a,2\n");
+        assert!(iter.next_group().is_none());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let bytes: &[u8] = b"a,1\na,\xff\na,2\nb,3\n";
+
+        let mut iter = bytes.lines_group_by_key(field);
+        assert_eq!(iter.next_group().unwrap().unwrap_err().kind(), io::ErrorKind::InvalidData);
+
+        // the invalid line is skipped and the group continues
+        assert_eq!(iter.next_group().unwrap().unwrap(), "a,1\na,2\n");
+        assert_eq!(iter.next_group().unwrap().unwrap(), "b,3\n");
+        assert!(iter.next_group().is_none());
+    }
+}
@@ -0,0 +1,205 @@
+use std::iter::FusedIterator;
+use std::ops::{Range, Sub};
+use std::{fmt, mem};
+
+/// Decides if an interval starting at `start` must be coalesced
+/// with the intervals merged so far, ending at `end`.
+pub trait Tolerance<T> {
+    /// Returns `true` if the interval starting at `start` must be
+    /// merged with the intervals ending at `end`.
+    fn reaches(&self, end: &T, start: &T) -> bool;
+}
+
+/// Coalesces the intervals that overlap or touch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoGap;
+
+impl<T: PartialOrd> Tolerance<T> for NoGap {
+    #[inline]
+    fn reaches(&self, end: &T, start: &T) -> bool {
+        start <= end
+    }
+}
+
+/// Coalesces the intervals that are separated by at most the given gap.
+///
+/// The gap between two intervals is computed by subtracting the end of the first one
+/// from the start of the second one, it can't overflow as the start is greater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxGap<G>(pub G);
+
+impl<T, G> Tolerance<T> for MaxGap<G>
+where T: PartialOrd + Clone + Sub<Output = G>,
+      G: PartialOrd,
+{
+    #[inline]
+    fn reaches(&self, end: &T, start: &T) -> bool {
+        start <= end || start.clone() - end.clone() <= self.0
+    }
+}
+
+/// The key function of the intervals coalesced without a key.
+pub type IntervalKey<T> = fn(&Range<T>) -> Range<T>;
+
+macro_rules! coalesce_intervals {
+    (struct $name:ident, $elem:ty, $split:ident) => {
+        impl<'a, E: 'a, F, T> $name<'a, E, F, NoGap>
+        where F: FnMut(&E) -> Range<T>,
+              T: PartialOrd,
+        {
+            pub fn new(slice: $elem, func: F) -> Self {
+                $name { slice, func, tolerance: NoGap }
+            }
+        }
+
+        impl<'a, E: 'a, F> $name<'a, E, F, NoGap> {
+            /// Also coalesces the intervals that are separated by at most `gap`.
+            pub fn max_gap<G>(self, gap: G) -> $name<'a, E, F, MaxGap<G>> {
+                $name { slice: self.slice, func: self.func, tolerance: MaxGap(gap) }
+            }
+        }
+
+        impl<'a, E: 'a, F, G> $name<'a, E, F, G> {
+            /// Returns the remainder of the original slice that is going to be
+            /// returned by the iterator.
+            pub fn remainder(&self) -> &[E] {
+                &self.slice
+            }
+        }
+
+        impl<'a, E: 'a, F, T, G> std::iter::Iterator for $name<'a, E, F, G>
+        where F: FnMut(&E) -> Range<T>,
+              T: PartialOrd,
+              G: Tolerance<T>,
+        {
+            type Item = (Range<T>, $elem);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (first, tail) = self.slice.split_first()?;
+
+                let Range { start, mut end } = (self.func)(first);
+                let mut len = 1;
+                for x in tail {
+                    let interval = (self.func)(x);
+                    if !self.tolerance.reaches(&end, &interval.start) { break }
+                    if interval.end > end { end = interval.end }
+                    len += 1;
+                }
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len);
+                self.slice = tail;
+                Some((start..end, head))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+            }
+        }
+
+        impl<'a, E: 'a, F, T, G> FusedIterator for $name<'a, E, F, G>
+        where F: FnMut(&E) -> Range<T>,
+              T: PartialOrd,
+              G: Tolerance<T>,
+        { }
+
+        impl<'a, E: 'a + fmt::Debug, F, G: fmt::Debug> fmt::Debug for $name<'a, E, F, G> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("slice", &self.slice)
+                    .field("tolerance", &self.tolerance)
+                    .finish()
+            }
+        }
+    }
+}
+
+/// An iterator that coalesces the intervals of a slice sorted by start that overlap or touch,
+/// returning the merged interval along with the group of source elements.
+///
+/// An element is coalesced if it starts before the greatest end of the elements merged so far,
+/// therefore the groups can only be found from the front.
+pub struct CoalesceIntervals<'a, E: 'a, F, G = NoGap> {
+    slice: &'a [E],
+    func: F,
+    tolerance: G,
+}
+
+coalesce_intervals!{ struct CoalesceIntervals, &'a [E], split_at }
+
+/// An iterator that coalesces the intervals of a *mutable* slice sorted by start that overlap
+/// or touch, returning the merged interval along with the group of source elements.
+///
+/// An element is coalesced if it starts before the greatest end of the elements merged so far,
+/// therefore the groups can only be found from the front.
+pub struct CoalesceIntervalsMut<'a, E: 'a, F, G = NoGap> {
+    slice: &'a mut [E],
+    func: F,
+    tolerance: G,
+}
+
+coalesce_intervals!{ struct CoalesceIntervalsMut, &'a mut [E], split_at_mut }
+
+/// Coalesces the intervals of a slice sorted by start that overlap or touch.
+///
+/// ```rust
+/// use slice_group_by::coalesce_intervals;
+///
+/// let intervals = &[1..3, 2..6, 6..7, 9..10, 12..14, 13..14];
+///
+/// let mut iter = coalesce_intervals(intervals);
+///
+/// assert_eq!(iter.next(), Some((1..7, &intervals[..3])));
+/// assert_eq!(iter.next(), Some((9..10, &intervals[3..4])));
+/// assert_eq!(iter.next(), Some((12..14, &intervals[4..])));
+/// assert_eq!(iter.next(), None);
+///
+/// let mut iter = coalesce_intervals(intervals).max_gap(3);
+///
+/// assert_eq!(iter.next(), Some((1..14, &intervals[..])));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn coalesce_intervals<T>(slice: &[Range<T>]) -> CoalesceIntervals<'_, Range<T>, IntervalKey<T>>
+where T: PartialOrd + Clone,
+{
+    CoalesceIntervals::new(slice, Range::clone)
+}
+
+/// Coalesces the intervals of the elements of a slice sorted by interval start
+/// that overlap or touch, the interval of an element being given by the function.
+///
+/// ```rust
+/// use slice_group_by::coalesce_intervals_by_key;
+///
+/// let bookings = &[("ann", 9..11), ("bob", 10..12), ("eve", 14..15)];
+///
+/// let mut iter = coalesce_intervals_by_key(bookings, |b| b.1.clone());
+///
+/// assert_eq!(iter.next(), Some((9..12, &bookings[..2])));
+/// assert_eq!(iter.next(), Some((14..15, &bookings[2..])));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn coalesce_intervals_by_key<E, F, T>(slice: &[E], func: F) -> CoalesceIntervals<'_, E, F>
+where F: FnMut(&E) -> Range<T>,
+      T: PartialOrd,
+{
+    CoalesceIntervals::new(slice, func)
+}
+
+/// Coalesces the intervals of a *mutable* slice sorted by start that overlap or touch.
+pub fn coalesce_intervals_mut<T>(slice: &mut [Range<T>]) -> CoalesceIntervalsMut<'_, Range<T>, IntervalKey<T>>
+where T: PartialOrd + Clone,
+{
+    CoalesceIntervalsMut::new(slice, Range::clone)
+}
+
+/// Coalesces the intervals of the elements of a *mutable* slice sorted by interval start
+/// that overlap or touch, the interval of an element being given by the function.
+pub fn coalesce_intervals_by_key_mut<E, F, T>(slice: &mut [E], func: F) -> CoalesceIntervalsMut<'_, E, F>
+where F: FnMut(&E) -> Range<T>,
+      T: PartialOrd,
+{
+    CoalesceIntervalsMut::new(slice, func)
+}
@@ -0,0 +1,85 @@
+mod coalesce_intervals;
+
+pub use self::coalesce_intervals::{
+    coalesce_intervals,
+    coalesce_intervals_by_key,
+    coalesce_intervals_mut,
+    coalesce_intervals_by_key_mut,
+    CoalesceIntervals,
+    CoalesceIntervalsMut,
+    IntervalKey,
+    Tolerance,
+    NoGap,
+    MaxGap,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_max_end() {
+        // the second interval is contained in the first one,
+        // the third one overlaps the first but not the second
+        let intervals = &[0..10, 1..2, 5..12, 12..13, 14..15];
+
+        let groups: Vec<_> = coalesce_intervals(intervals).map(|(r, g)| (r, g.len())).collect();
+        assert_eq!(groups, vec![(0..13, 4), (14..15, 1)]);
+    }
+
+    #[test]
+    fn empty_intervals() {
+        let intervals = &[3..3, 3..3, 4..4];
+
+        let groups: Vec<_> = coalesce_intervals(intervals).collect();
+        assert_eq!(groups, vec![(3..3, &intervals[..2]), (4..4, &intervals[2..])]);
+
+        let intervals: &[std::ops::Range<i32>] = &[];
+        assert_eq!(coalesce_intervals(intervals).next(), None);
+    }
+
+    #[test]
+    fn gap_tolerance() {
+        let intervals = &[0.0..1.0, 1.5..2.0, 2.75..3.0];
+
+        let groups: Vec<_> = coalesce_intervals(intervals).max_gap(0.5).map(|(r, _)| r).collect();
+        assert_eq!(groups, vec![0.0..2.0, 2.75..3.0]);
+
+        let groups: Vec<_> = coalesce_intervals(intervals).max_gap(1.0).map(|(r, _)| r).collect();
+        assert_eq!(groups, vec![0.0..3.0]);
+    }
+
+    #[test]
+    fn gap_near_max() {
+        let intervals = &[0..10, u32::MAX - 5..u32::MAX, u32::MAX..u32::MAX];
+
+        let groups: Vec<_> = coalesce_intervals(intervals).max_gap(3).map(|(r, _)| r).collect();
+        assert_eq!(groups, vec![0..10, u32::MAX - 5..u32::MAX]);
+
+        let groups: Vec<_> = coalesce_intervals(intervals).max_gap(u32::MAX).map(|(r, _)| r).collect();
+        assert_eq!(groups, vec![0..u32::MAX]);
+    }
+
+    #[test]
+    fn mut_groups() {
+        let slice = &mut [(0..2, 0), (1..3, 0), (5..6, 0), (6..8, 0)];
+
+        let iter = coalesce_intervals_by_key_mut(slice, |x| x.0.clone());
+        assert_eq!(iter.remainder().len(), 4);
+
+        for (id, (_, group)) in iter.enumerate() {
+            group.iter_mut().for_each(|x| x.1 = id);
+        }
+
+        let ids: Vec<_> = slice.iter().map(|x| x.1).collect();
+        assert_eq!(ids, vec![0, 0, 1, 1]);
+
+        let intervals = &mut [0..2, 3..4];
+        let mut iter = coalesce_intervals_mut(intervals).max_gap(1);
+        let (range, group) = iter.next().unwrap();
+        assert_eq!(range, 0..4);
+        group[1].end = 5;
+        assert!(iter.next().is_none());
+        assert_eq!(intervals, &[0..2, 3..5]);
+    }
+}
@@ -0,0 +1,229 @@
+use std::iter::FusedIterator;
+use std::{fmt, mem};
+use crate::{run_len, SearchMode};
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T> Sealed for &[T] {}
+    impl<T> Sealed for &mut [T] {}
+    impl Sealed for () {}
+
+    macro_rules! impl_sealed_tuple {
+        ($($name:ident)+) => {
+            impl<$($name: super::Columns),+> Sealed for ($($name,)+) {}
+        }
+    }
+
+    impl_sealed_tuple!{ A }
+    impl_sealed_tuple!{ A B }
+    impl_sealed_tuple!{ A B C }
+    impl_sealed_tuple!{ A B C D }
+    impl_sealed_tuple!{ A B C D E }
+    impl_sealed_tuple!{ A B C D E F }
+    impl_sealed_tuple!{ A B C D E F G }
+    impl_sealed_tuple!{ A B C D E F G H }
+}
+
+/// The columns that are split along with the key column by the [`ColumnGroupBy`] iterator,
+/// either a shared or a *mutable* slice, the unit type or a tuple of up to eight columns.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+///
+/// [`ColumnGroupBy`]: struct.ColumnGroupBy.html
+pub trait Columns: Default + private::Sealed {
+    /// Returns `true` if all the columns have the given length.
+    fn has_len(&self, len: usize) -> bool;
+
+    /// Divides all the columns into two at an index.
+    fn split_at(self, mid: usize) -> (Self, Self);
+}
+
+impl<T> Columns for &[T] {
+    #[inline]
+    fn has_len(&self, len: usize) -> bool {
+        <[T]>::len(self) == len
+    }
+
+    #[inline]
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        <[T]>::split_at(self, mid)
+    }
+}
+
+impl<T> Columns for &mut [T] {
+    #[inline]
+    fn has_len(&self, len: usize) -> bool {
+        <[T]>::len(self) == len
+    }
+
+    #[inline]
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        <[T]>::split_at_mut(self, mid)
+    }
+}
+
+impl Columns for () {
+    #[inline]
+    fn has_len(&self, _len: usize) -> bool {
+        true
+    }
+
+    #[inline]
+    fn split_at(self, _mid: usize) -> (Self, Self) {
+        ((), ())
+    }
+}
+
+macro_rules! impl_columns_tuple {
+    ($($name:ident $var:ident $index:tt)+) => {
+        impl<$($name: Columns),+> Columns for ($($name,)+) {
+            #[inline]
+            fn has_len(&self, len: usize) -> bool {
+                $(self.$index.has_len(len))&&+
+            }
+
+            #[inline]
+            fn split_at(self, mid: usize) -> (Self, Self) {
+                $(let $var = self.$index.split_at(mid);)+
+                (($($var.0,)+), ($($var.1,)+))
+            }
+        }
+    }
+}
+
+impl_columns_tuple!{ A a 0 }
+impl_columns_tuple!{ A a 0 B b 1 }
+impl_columns_tuple!{ A a 0 B b 1 C c 2 }
+impl_columns_tuple!{ A a 0 B b 1 C c 2 D d 3 }
+impl_columns_tuple!{ A a 0 B b 1 C c 2 D d 3 E e 4 }
+impl_columns_tuple!{ A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 }
+impl_columns_tuple!{ A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 }
+impl_columns_tuple!{ A a 0 B b 1 C c 2 D d 3 E e 4 F f 5 G g 6 H h 7 }
+
+/// An iterator that will return non-overlapping groups of equal keys in a key column
+/// along with the aligned groups of the other columns, for data stored
+/// as a struct of arrays.
+///
+/// The keys are compared using the [`PartialEq::eq`] method, the key column must be
+/// sorted, or at least grouped, to use the *binary* or *exponential search* method.
+///
+/// [`PartialEq::eq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html#tymethod.eq
+pub struct ColumnGroupBy<'a, K: 'a, C> {
+    keys: &'a [K],
+    columns: C,
+    mode: SearchMode,
+}
+
+impl<'a, K: 'a, C> ColumnGroupBy<'a, K, C>
+where K: PartialEq,
+      C: Columns,
+{
+    /// Creates an iterator on the groups of the key column and of the other columns
+    /// using the given search method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the other columns do not have the same length as the key column.
+    ///
+    /// ```rust
+    /// use slice_group_by::{ColumnGroupBy, SearchMode};
+    ///
+    /// let ids = &[1, 1, 2, 3, 3];
+    /// let prices = &[1.5, 2.0, 9.9, 0.5, 0.5];
+    /// let qty = &mut [10, 20, 30, 40, 50];
+    ///
+    /// let iter = ColumnGroupBy::new(ids, (&prices[..], &mut qty[..]), SearchMode::Binary);
+    ///
+    /// for (ids, (prices, qty)) in iter {
+    ///     let total: f64 = prices.iter().zip(&*qty).map(|(p, &q)| p * q as f64).sum();
+    ///     qty.iter_mut().for_each(|q| *q = total as u32);
+    ///     assert!(ids.iter().all(|&id| id == ids[0]));
+    /// }
+    ///
+    /// assert_eq!(qty, &[55, 55, 297, 45, 45]);
+    /// ```
+    pub fn new(keys: &'a [K], columns: C, mode: SearchMode) -> Self {
+        assert!(columns.has_len(keys.len()), "columns must have the same length as the key column");
+        ColumnGroupBy { keys, columns, mode }
+    }
+}
+
+impl<'a, K: 'a, C> ColumnGroupBy<'a, K, C> {
+    /// Returns the remainder of the original key column that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[K] {
+        self.keys
+    }
+
+    /// Returns the remainder of the original columns that is going to be
+    /// returned by the iterator.
+    pub fn into_remainder(self) -> (&'a [K], C) {
+        (self.keys, self.columns)
+    }
+}
+
+impl<'a, K: 'a, C> Iterator for ColumnGroupBy<'a, K, C>
+where K: PartialEq,
+      C: Columns,
+{
+    type Item = (&'a [K], C);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let keys = self.keys;
+        let first = keys.first()?;
+        let len = run_len(keys.len(), self.mode, |i| keys[i] == *first);
+
+        let (head, tail) = keys.split_at(len);
+        let (head_columns, tail_columns) = mem::take(&mut self.columns).split_at(len);
+        self.keys = tail;
+        self.columns = tail_columns;
+        Some((head, head_columns))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.keys.is_empty() { (0, Some(0)) } else { (1, Some(self.keys.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, K: 'a, C> DoubleEndedIterator for ColumnGroupBy<'a, K, C>
+where K: PartialEq,
+      C: Columns,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let keys = self.keys;
+        let last = keys.last()?;
+        let last_index = keys.len() - 1;
+        let len = run_len(keys.len(), self.mode, |i| keys[last_index - i] == *last);
+
+        let mid = keys.len() - len;
+        let (head, tail) = keys.split_at(mid);
+        let (head_columns, tail_columns) = mem::take(&mut self.columns).split_at(mid);
+        self.keys = head;
+        self.columns = head_columns;
+        Some((tail, tail_columns))
+    }
+}
+
+impl<'a, K: 'a, C> FusedIterator for ColumnGroupBy<'a, K, C>
+where K: PartialEq,
+      C: Columns,
+{ }
+
+impl<'a, K: 'a + fmt::Debug, C: fmt::Debug> fmt::Debug for ColumnGroupBy<'a, K, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ColumnGroupBy")
+            .field("keys", &self.keys)
+            .field("columns", &self.columns)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
@@ -0,0 +1,70 @@
+mod column_group;
+
+pub use self::column_group::{ColumnGroupBy, Columns};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SearchMode;
+
+    const MODES: [SearchMode; 3] = [SearchMode::Linear, SearchMode::Binary, SearchMode::Exponential];
+
+    #[test]
+    fn aligned_columns() {
+        let ids: &[u64] = &[1, 1, 2, 3, 3, 3];
+        let prices: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let names = &["a", "b", "c", "d", "e", "f"];
+
+        for &mode in &MODES {
+            let mut iter = ColumnGroupBy::new(ids, (prices, &names[..]), mode);
+            assert_eq!(iter.next(), Some((&ids[..2], (&prices[..2], &names[..2]))));
+            assert_eq!(iter.next_back(), Some((&ids[3..], (&prices[3..], &names[3..]))));
+            assert_eq!(iter.remainder(), &[2]);
+            assert_eq!(iter.next(), Some((&ids[2..3], (&prices[2..3], &names[2..3]))));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+
+    #[test]
+    fn mutable_columns() {
+        let ids: &[u64] = &[7, 7, 7, 8, 9, 9];
+        let prices: &[f64] = &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let qty: &mut [u32] = &mut [0; 6];
+
+        for (ids, (prices, qty)) in ColumnGroupBy::new(ids, (prices, &mut *qty), SearchMode::Exponential) {
+            assert_eq!(ids.len(), prices.len());
+            let count = prices.len() as u32;
+            qty.iter_mut().for_each(|q| *q = count);
+        }
+
+        assert_eq!(qty, &[3, 3, 3, 1, 2, 2]);
+    }
+
+    #[test]
+    fn modes_agree() {
+        let ids: Vec<u32> = (0..1000).map(|i| i * i / 1013).collect();
+        let values: Vec<u32> = (0..1000).collect();
+
+        let linear: Vec<_> = ColumnGroupBy::new(&ids, &values[..], SearchMode::Linear).collect();
+        for &mode in &MODES {
+            let groups: Vec<_> = ColumnGroupBy::new(&ids, &values[..], mode).collect();
+            assert_eq!(groups, linear);
+
+            let mut rev: Vec<_> = ColumnGroupBy::new(&ids, &values[..], mode).rev().collect();
+            rev.reverse();
+            assert_eq!(rev, linear);
+        }
+
+        let groups: Vec<_> = ColumnGroupBy::new(&ids, (), SearchMode::Binary).map(|(k, ())| k).collect();
+        assert_eq!(groups.len(), linear.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unequal_lengths() {
+        let ids = &[1, 2, 3];
+        let prices = &[1.0, 2.0];
+        let _ = ColumnGroupBy::new(ids, (&[0u8; 3][..], &prices[..]), SearchMode::Linear);
+    }
+}
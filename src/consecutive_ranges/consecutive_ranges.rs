@@ -0,0 +1,205 @@
+use std::cmp::Ordering::{Less, Greater};
+use std::convert::TryFrom;
+use std::iter::FusedIterator;
+use std::ops::RangeInclusive;
+use std::fmt;
+use crate::{exponential_search_by, offset_from};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A primitive integer that can be compressed into ranges of consecutive values.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait ConsecutiveInt: Copy + Ord + private::Sealed {
+    /// The stride between two consecutive integers.
+    const ONE: Self;
+
+    /// Returns `true` if the integer is strictly positive.
+    fn is_positive(self) -> bool;
+
+    /// Returns `self + count * step`, or `None` on overflow.
+    fn add_steps(self, count: usize, step: Self) -> Option<Self>;
+}
+
+macro_rules! consecutive_int {
+    ($($int:ty: $uint:ty),*) => {
+        $(
+            impl private::Sealed for $int {}
+
+            impl ConsecutiveInt for $int {
+                const ONE: Self = 1;
+
+                #[inline]
+                fn is_positive(self) -> bool {
+                    self > 0
+                }
+
+                #[inline]
+                fn add_steps(self, count: usize, step: Self) -> Option<Self> {
+                    // the offset is computed in the unsigned type as it can be greater
+                    // than the maximum of a signed type, the step is always positive
+                    let count = <$uint>::try_from(count).ok()?;
+                    let offset = count.checked_mul(step as $uint)?;
+                    let headroom = <$int>::MAX.wrapping_sub(self) as $uint;
+                    if offset > headroom { return None }
+                    Some(self.wrapping_add(offset as $int))
+                }
+            }
+        )*
+    }
+}
+
+consecutive_int!{
+    u8: u8, u16: u16, u32: u32, u64: u64, u128: u128, usize: usize,
+    i8: u8, i16: u16, i32: u32, i64: u64, i128: u128, isize: usize
+}
+
+/// An iterator that will return the ranges of consecutive integers of a sorted slice,
+/// the integers of a range being separated by a constant step.
+///
+/// As `value - index * step` is constant within a range, the end of a range
+/// is found using an exponential search instead of looking at every element.
+pub struct ConsecutiveRanges<'a, T: 'a> {
+    slice: &'a [T],
+    step: T,
+}
+
+impl<'a, T: 'a> ConsecutiveRanges<'a, T>
+where T: ConsecutiveInt,
+{
+    /// Creates an iterator of ranges of integers separated by `step`.
+    ///
+    /// The slice must be sorted and two neighbouring integers must be separated
+    /// by at least `step`, the returned ranges are unspecified otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not strictly positive.
+    pub fn new(slice: &'a [T], step: T) -> Self {
+        assert!(step.is_positive(), "step must be strictly positive");
+        ConsecutiveRanges { slice, step }
+    }
+}
+
+impl<'a, T: 'a> ConsecutiveRanges<'a, T> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<'a, T: 'a> Iterator for ConsecutiveRanges<'a, T>
+where T: ConsecutiveInt,
+{
+    type Item = RangeInclusive<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&first, tail) = self.slice.split_first()?;
+        let step = self.step;
+
+        let start = self.slice.as_ptr();
+        let predicate = |x: &T| {
+            let count = unsafe { offset_from(x, start) };
+            if first.add_steps(count, step) == Some(*x) { Less } else { Greater }
+        };
+        let index = exponential_search_by(tail, predicate).unwrap_err();
+
+        let last = self.slice[index];
+        self.slice = &self.slice[index + 1..];
+
+        Some(first..=last)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.is_empty() { return (0, Some(0)) }
+        (1, Some(self.slice.len()))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for ConsecutiveRanges<'a, T>
+where T: ConsecutiveInt,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (&last, head) = self.slice.split_last()?;
+        let step = self.step;
+
+        let end: *const T = &self.slice[head.len()];
+        let predicate = |x: &T| {
+            let count = unsafe { offset_from(end, x) };
+            if x.add_steps(count, step) == Some(last) { Greater } else { Less }
+        };
+        let index = exponential_search_by(head, predicate).unwrap_err();
+
+        let first = self.slice[index];
+        self.slice = &self.slice[..index];
+
+        Some(first..=last)
+    }
+}
+
+impl<'a, T: 'a> FusedIterator for ConsecutiveRanges<'a, T>
+where T: ConsecutiveInt,
+{ }
+
+impl<'a, T: 'a + fmt::Debug> fmt::Debug for ConsecutiveRanges<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ConsecutiveRanges")
+            .field("remainder", &self.slice)
+            .field("step", &self.step)
+            .finish()
+    }
+}
+
+/// Returns an iterator over the ranges of consecutive integers of a sorted slice.
+///
+/// The slice must be sorted and must not contain duplicates,
+/// the returned ranges are unspecified otherwise.
+///
+/// ```rust
+/// use slice_group_by::consecutive_ranges;
+///
+/// let ids = &[1, 2, 3, 7, 8, 10];
+///
+/// let ranges: Vec<_> = consecutive_ranges(ids).collect();
+/// assert_eq!(ranges, vec![1..=3, 7..=8, 10..=10]);
+///
+/// let ranges: Vec<_> = consecutive_ranges(ids).rev().collect();
+/// assert_eq!(ranges, vec![10..=10, 7..=8, 1..=3]);
+/// ```
+pub fn consecutive_ranges<T>(slice: &[T]) -> ConsecutiveRanges<'_, T>
+where T: ConsecutiveInt,
+{
+    ConsecutiveRanges::new(slice, T::ONE)
+}
+
+/// Returns an iterator over the ranges of integers separated by `step` of a sorted slice.
+///
+/// The slice must be sorted and two neighbouring integers must be separated
+/// by at least `step`, the returned ranges are unspecified otherwise.
+///
+/// # Panics
+///
+/// Panics if `step` is not strictly positive.
+///
+/// ```rust
+/// use slice_group_by::consecutive_ranges_by_step;
+///
+/// let offsets = &[0, 4, 8, 16, 20, 28];
+///
+/// let ranges: Vec<_> = consecutive_ranges_by_step(offsets, 4).collect();
+/// assert_eq!(ranges, vec![0..=8, 16..=20, 28..=28]);
+/// ```
+pub fn consecutive_ranges_by_step<T>(slice: &[T], step: T) -> ConsecutiveRanges<'_, T>
+where T: ConsecutiveInt,
+{
+    ConsecutiveRanges::new(slice, step)
+}
@@ -0,0 +1,101 @@
+mod consecutive_ranges;
+
+pub use self::consecutive_ranges::{
+    consecutive_ranges,
+    consecutive_ranges_by_step,
+    ConsecutiveRanges,
+    ConsecutiveInt,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_runs() {
+        let mut ids: Vec<u32> = (0..1000).collect();
+        ids.extend(2000..2001);
+        ids.extend(3000..3500);
+
+        let ranges: Vec<_> = consecutive_ranges(&ids).collect();
+        assert_eq!(ranges, vec![0..=999, 2000..=2000, 3000..=3499]);
+
+        let ranges: Vec<_> = consecutive_ranges(&ids).rev().collect();
+        assert_eq!(ranges, vec![3000..=3499, 2000..=2000, 0..=999]);
+    }
+
+    #[test]
+    fn double_ended() {
+        let ids = &[-5i8, -4, -2, 0, 1, 2, 127];
+
+        let mut iter = consecutive_ranges(ids);
+        assert_eq!(iter.next(), Some(-5..=-4));
+        assert_eq!(iter.next_back(), Some(127..=127));
+        assert_eq!(iter.next_back(), Some(0..=2));
+        assert_eq!(iter.remainder(), &[-2]);
+        assert_eq!(iter.next(), Some(-2..=-2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn overflowing_values() {
+        let ids = &[u8::MAX - 1, u8::MAX];
+
+        let ranges: Vec<_> = consecutive_ranges(ids).collect();
+        assert_eq!(ranges, vec![254..=255]);
+
+        let ids: Vec<u8> = (0..=255).collect();
+        let ranges: Vec<_> = consecutive_ranges(&ids).rev().collect();
+        assert_eq!(ranges, vec![0..=255]);
+    }
+
+    #[test]
+    fn full_range() {
+        let ids: Vec<u8> = (0..=255).collect();
+        assert_eq!(consecutive_ranges(&ids).collect::<Vec<_>>(), vec![0..=255]);
+        assert_eq!(consecutive_ranges(&ids).rev().collect::<Vec<_>>(), vec![0..=255]);
+
+        let ids: Vec<i8> = (-128..=127).collect();
+        assert_eq!(consecutive_ranges(&ids).collect::<Vec<_>>(), vec![-128..=127]);
+        assert_eq!(consecutive_ranges(&ids).rev().collect::<Vec<_>>(), vec![-128..=127]);
+
+        let ids: Vec<i16> = (i16::MIN..=i16::MAX).collect();
+        assert_eq!(consecutive_ranges(&ids).collect::<Vec<_>>(), vec![i16::MIN..=i16::MAX]);
+        assert_eq!(consecutive_ranges(&ids).rev().collect::<Vec<_>>(), vec![i16::MIN..=i16::MAX]);
+    }
+
+    #[test]
+    fn signed_steps_greater_than_max() {
+        let ids = &[-100i8, 0, 100];
+
+        let ranges: Vec<_> = consecutive_ranges_by_step(ids, 100).collect();
+        assert_eq!(ranges, vec![-100..=100]);
+
+        let ranges: Vec<_> = consecutive_ranges_by_step(ids, 100).rev().collect();
+        assert_eq!(ranges, vec![-100..=100]);
+    }
+
+    #[test]
+    fn stepped_ranges() {
+        let ids = &[10u64, 20, 30, 35, 45, 55, 100];
+
+        let ranges: Vec<_> = consecutive_ranges_by_step(ids, 10).collect();
+        assert_eq!(ranges, vec![10..=30, 35..=55, 100..=100]);
+
+        let ranges: Vec<_> = consecutive_ranges_by_step(ids, 10).rev().collect();
+        assert_eq!(ranges, vec![100..=100, 35..=55, 10..=30]);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let ids: &[usize] = &[];
+        assert_eq!(consecutive_ranges(ids).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_step() {
+        consecutive_ranges_by_step(&[1, 2, 3], 0);
+    }
+}
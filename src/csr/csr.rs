@@ -0,0 +1,310 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::fmt;
+use crate::ExponentialGroupBy;
+
+/// Returns the start offsets of the groups of pairs with the same key,
+/// followed by the number of pairs.
+fn group_offsets<K: PartialEq, V>(pairs: &[(K, V)]) -> Vec<usize> {
+    let mut offsets = Vec::with_capacity(1);
+    offsets.push(0);
+
+    // this is an ExponentialGroupByKey on the keys, but its key function cannot return
+    // a reference borrowed from the pair and the keys are not required to be Clone
+    let mut end = 0;
+    for group in ExponentialGroupBy::new(pairs, |a: &(K, V), b: &(K, V)| a.0 == b.0) {
+        end += group.len();
+        offsets.push(end);
+    }
+
+    offsets
+}
+
+/// A compressed sparse row representation of sorted `(key, value)` pairs,
+/// the values of the `i`th key are in `values[offsets[i]..offsets[i + 1]]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Csr<K, V> {
+    keys: Vec<K>,
+    offsets: Vec<usize>,
+    values: Vec<V>,
+}
+
+impl<K, V> Csr<K, V> {
+    /// Builds a `Csr` from pairs sorted by key, the boundaries
+    /// of the keys being found using an exponential search.
+    ///
+    /// ```rust
+    /// use slice_group_by::Csr;
+    ///
+    /// let postings = &[("fox", 1), ("fox", 4), ("quick", 1), ("the", 1), ("the", 2)];
+    ///
+    /// let csr = Csr::from_sorted_pairs(postings);
+    ///
+    /// assert_eq!(csr.keys(), &["fox", "quick", "the"]);
+    /// assert_eq!(csr.offsets(), &[0, 2, 3, 5]);
+    /// assert_eq!(csr.values(), &[1, 4, 1, 1, 2]);
+    ///
+    /// assert_eq!(csr.get(&"the"), &[1, 2]);
+    /// assert_eq!(csr.get(&"dog"), &[]);
+    /// ```
+    pub fn from_sorted_pairs(pairs: &[(K, V)]) -> Csr<K, V>
+    where K: PartialEq + Clone,
+          V: Clone,
+    {
+        let offsets = group_offsets(pairs);
+        let keys = offsets[..offsets.len() - 1].iter().map(|&i| pairs[i].0.clone()).collect();
+        let values = pairs.iter().map(|(_, v)| v.clone()).collect();
+
+        Csr { keys, offsets, values }
+    }
+
+    /// Returns the distinct keys, in sorted order.
+    pub fn keys(&self) -> &[K] {
+        &self.keys
+    }
+
+    /// Returns the offsets of the values of every key, followed by the number of values.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the values of all the keys.
+    pub fn values(&self) -> &[V] {
+        &self.values
+    }
+
+    /// Returns the number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if there is no key.
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Returns the values of the given key, binary searched in the keys,
+    /// the returned slice is empty if the key is not present.
+    pub fn get(&self, key: &K) -> &[V]
+    where K: Ord,
+    {
+        match self.keys.binary_search(key) {
+            Ok(i) => &self.values[self.offsets[i]..self.offsets[i + 1]],
+            Err(_) => &[],
+        }
+    }
+
+    /// Returns an iterator over the keys along with their values.
+    pub fn iter(&self) -> CsrIter<'_, K, V> {
+        CsrIter { csr: self, range: 0..self.keys.len() }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Csr<K, V> {
+    type Item = (&'a K, &'a [V]);
+    type IntoIter = CsrIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the keys of a [`Csr`] along with their values.
+///
+/// [`Csr`]: struct.Csr.html
+pub struct CsrIter<'a, K: 'a, V: 'a> {
+    csr: &'a Csr<K, V>,
+    range: Range<usize>,
+}
+
+impl<'a, K: 'a, V: 'a> CsrIter<'a, K, V> {
+    fn entry(&self, i: usize) -> (&'a K, &'a [V]) {
+        let csr = self.csr;
+        (&csr.keys[i], &csr.values[csr.offsets[i]..csr.offsets[i + 1]])
+    }
+}
+
+impl<'a, K: 'a, V: 'a> Iterator for CsrIter<'a, K, V> {
+    type Item = (&'a K, &'a [V]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.entry(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, K: 'a, V: 'a> DoubleEndedIterator for CsrIter<'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.entry(i))
+    }
+}
+
+impl<'a, K: 'a, V: 'a> ExactSizeIterator for CsrIter<'a, K, V> { }
+
+impl<'a, K: 'a, V: 'a> FusedIterator for CsrIter<'a, K, V> { }
+
+impl<'a, K: 'a + fmt::Debug, V: 'a + fmt::Debug> fmt::Debug for CsrIter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CsrIter")
+            .field("keys", &&self.csr.keys[self.range.clone()])
+            .finish()
+    }
+}
+
+/// A compressed sparse row view over sorted `(key, value)` pairs that does not copy them,
+/// the pairs of the `i`th key are in `pairs[offsets[i]..offsets[i + 1]]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrRef<'a, K: 'a, V: 'a> {
+    pairs: &'a [(K, V)],
+    offsets: Vec<usize>,
+}
+
+impl<'a, K: 'a, V: 'a> CsrRef<'a, K, V> {
+    /// Builds a `CsrRef` from pairs sorted by key, the boundaries
+    /// of the keys being found using an exponential search.
+    ///
+    /// ```rust
+    /// use slice_group_by::CsrRef;
+    ///
+    /// let edges = &[(0, 1), (0, 2), (1, 2), (3, 0)];
+    ///
+    /// let csr = CsrRef::from_sorted_pairs(edges);
+    ///
+    /// assert_eq!(csr.offsets(), &[0, 2, 3, 4]);
+    /// assert_eq!(csr.get(&0), &[(0, 1), (0, 2)]);
+    /// assert_eq!(csr.values(&3).collect::<Vec<_>>(), vec![&0]);
+    /// assert_eq!(csr.get(&2), &[]);
+    /// ```
+    pub fn from_sorted_pairs(pairs: &'a [(K, V)]) -> CsrRef<'a, K, V>
+    where K: PartialEq,
+    {
+        CsrRef { pairs, offsets: group_offsets(pairs) }
+    }
+
+    /// Returns the offsets of the pairs of every key, followed by the number of pairs.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /// Returns the number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns `true` if there is no key.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the key at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the keys.
+    pub fn key(&self, index: usize) -> &'a K {
+        &self.pairs[self.offsets[index]].0
+    }
+
+    /// Returns the pairs of the given key, binary searched in the keys,
+    /// the returned slice is empty if the key is not present.
+    pub fn get(&self, key: &K) -> &'a [(K, V)]
+    where K: Ord,
+    {
+        let starts = &self.offsets[..self.len()];
+        match starts.binary_search_by(|&i| self.pairs[i].0.cmp(key)) {
+            Ok(i) => &self.pairs[self.offsets[i]..self.offsets[i + 1]],
+            Err(_) => &[],
+        }
+    }
+
+    /// Returns the values of the given key.
+    pub fn values(&self, key: &K) -> impl DoubleEndedIterator<Item = &'a V> + ExactSizeIterator
+    where K: Ord,
+    {
+        self.get(key).iter().map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over the keys along with their pairs.
+    pub fn iter(&self) -> CsrRefIter<'_, 'a, K, V> {
+        CsrRefIter { csr: self, range: 0..self.len() }
+    }
+
+    /// Copies the keys and values into a `Csr`.
+    pub fn to_csr(&self) -> Csr<K, V>
+    where K: Clone,
+          V: Clone,
+    {
+        let keys = (0..self.len()).map(|i| self.key(i).clone()).collect();
+        let values = self.pairs.iter().map(|(_, v)| v.clone()).collect();
+        Csr { keys, offsets: self.offsets.clone(), values }
+    }
+}
+
+impl<'b, 'a, K: 'a, V: 'a> IntoIterator for &'b CsrRef<'a, K, V> {
+    type Item = (&'a K, &'a [(K, V)]);
+    type IntoIter = CsrRefIter<'b, 'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the keys of a [`CsrRef`] along with their pairs.
+///
+/// [`CsrRef`]: struct.CsrRef.html
+pub struct CsrRefIter<'b, 'a: 'b, K: 'a, V: 'a> {
+    csr: &'b CsrRef<'a, K, V>,
+    range: Range<usize>,
+}
+
+impl<'b, 'a, K: 'a, V: 'a> CsrRefIter<'b, 'a, K, V> {
+    fn entry(&self, i: usize) -> (&'a K, &'a [(K, V)]) {
+        let csr = self.csr;
+        (csr.key(i), &csr.pairs[csr.offsets[i]..csr.offsets[i + 1]])
+    }
+}
+
+impl<'b, 'a, K: 'a, V: 'a> Iterator for CsrRefIter<'b, 'a, K, V> {
+    type Item = (&'a K, &'a [(K, V)]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|i| self.entry(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'b, 'a, K: 'a, V: 'a> DoubleEndedIterator for CsrRefIter<'b, 'a, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|i| self.entry(i))
+    }
+}
+
+impl<'b, 'a, K: 'a, V: 'a> ExactSizeIterator for CsrRefIter<'b, 'a, K, V> { }
+
+impl<'b, 'a, K: 'a, V: 'a> FusedIterator for CsrRefIter<'b, 'a, K, V> { }
+
+impl<'b, 'a, K: 'a, V: 'a> fmt::Debug for CsrRefIter<'b, 'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CsrRefIter")
+            .field("range", &self.range)
+            .finish()
+    }
+}
@@ -0,0 +1,61 @@
+mod csr;
+
+pub use self::csr::{Csr, CsrIter, CsrRef, CsrRefIter};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csr_iter() {
+        let pairs = &[(1, 'a'), (1, 'b'), (2, 'c'), (5, 'd'), (5, 'e'), (5, 'f')];
+
+        let csr = Csr::from_sorted_pairs(pairs);
+
+        assert_eq!(csr.len(), 3);
+        let entries: Vec<_> = csr.iter().collect();
+        assert_eq!(entries, vec![(&1, &['a', 'b'][..]), (&2, &['c'][..]), (&5, &['d', 'e', 'f'][..])]);
+
+        let keys: Vec<_> = csr.iter().rev().map(|(k, _)| *k).collect();
+        assert_eq!(keys, vec![5, 2, 1]);
+
+        assert_eq!(csr.get(&5), &['d', 'e', 'f']);
+        assert_eq!(csr.get(&0), &[]);
+        assert_eq!(csr.get(&3), &[]);
+        assert_eq!(csr.get(&9), &[]);
+    }
+
+    #[test]
+    fn csr_ref_matches_csr() {
+        let pairs = &[(0, 10), (2, 20), (2, 21), (3, 30), (7, 70), (7, 71), (7, 72)];
+
+        let csr_ref = CsrRef::from_sorted_pairs(pairs);
+        let csr = Csr::from_sorted_pairs(pairs);
+
+        assert_eq!(csr_ref.to_csr(), csr);
+        assert_eq!(csr_ref.offsets(), csr.offsets());
+
+        for (key, group) in &csr_ref {
+            let values: Vec<_> = group.iter().map(|(_, v)| *v).collect();
+            assert_eq!(&values[..], csr.get(key));
+            assert_eq!(csr_ref.get(key), group);
+        }
+
+        assert_eq!(csr_ref.values(&7).rev().collect::<Vec<_>>(), vec![&72, &71, &70]);
+        assert_eq!(csr_ref.get(&1), &[]);
+    }
+
+    #[test]
+    fn empty_pairs() {
+        let pairs: &[(i32, i32)] = &[];
+
+        let csr = Csr::from_sorted_pairs(pairs);
+        assert!(csr.is_empty());
+        assert_eq!(csr.offsets(), &[0]);
+        assert_eq!(csr.get(&1), &[]);
+
+        let csr_ref = CsrRef::from_sorted_pairs(pairs);
+        assert!(csr_ref.is_empty());
+        assert!(csr_ref.iter().next().is_none());
+    }
+}
@@ -0,0 +1,299 @@
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+use std::{fmt, mem};
+use crate::{exponential_search_by, offset_from};
+
+/// Returns the byte index following the end of the record starting at `start`,
+/// including the `'\n'` record terminator.
+fn record_end(buffer: &[u8], start: usize) -> usize {
+    match buffer[start..].iter().position(|&b| b == b'\n') {
+        Some(i) => start + i + 1,
+        None => buffer.len(),
+    }
+}
+
+/// Returns the byte index of the start of the record that contains the byte at `index`.
+fn record_start(buffer: &[u8], index: usize) -> usize {
+    match buffer[..index].iter().rposition(|&b| b == b'\n') {
+        Some(i) => i + 1,
+        None => 0,
+    }
+}
+
+/// Returns the record without its `"\n"` or `"\r\n"` record terminator.
+fn trim_terminator(record: &[u8]) -> &[u8] {
+    let record = record.strip_suffix(b"\n").unwrap_or(record);
+    record.strip_suffix(b"\r").unwrap_or(record)
+}
+
+/// Returns the field at `index` of the record, `None` if the record has less fields.
+fn field(record: &[u8], delimiter: u8, index: usize) -> Option<&[u8]> {
+    trim_terminator(record).split(|&b| b == delimiter).nth(index)
+}
+
+#[cfg(feature = "quoted-fields")]
+mod quoted {
+    use super::trim_terminator;
+
+    /// Returns the byte index following the end of the record starting at `start`,
+    /// the `'\n'` between double quotes are part of the fields.
+    pub fn record_end(buffer: &[u8], start: usize) -> usize {
+        let mut in_quotes = false;
+        for (i, &b) in buffer[start..].iter().enumerate() {
+            match b {
+                b'"' => in_quotes = !in_quotes,
+                b'\n' if !in_quotes => return start + i + 1,
+                _ => (),
+            }
+        }
+        buffer.len()
+    }
+
+    /// Returns the raw field at `index` of the record, the delimiters
+    /// between double quotes are part of the fields.
+    pub fn field(record: &[u8], delimiter: u8, index: usize) -> Option<&[u8]> {
+        let record = trim_terminator(record);
+
+        let mut current = 0;
+        let mut start = 0;
+        let mut in_quotes = false;
+        for (i, &b) in record.iter().enumerate() {
+            if b == b'"' {
+                in_quotes = !in_quotes;
+            } else if b == delimiter && !in_quotes {
+                if current == index { return Some(&record[start..i]) }
+                current += 1;
+                start = i + 1;
+            }
+        }
+
+        if current == index { Some(&record[start..]) } else { None }
+    }
+
+    /// Returns the bytes of the field without its surrounding double quotes
+    /// and with the escaped double quotes (`""`) unescaped.
+    pub fn unquoted(field: &[u8]) -> Unquoted<'_> {
+        match field.split_first() {
+            Some((b'"', content)) => Unquoted { bytes: content, quoted: true },
+            _ => Unquoted { bytes: field, quoted: false },
+        }
+    }
+
+    pub struct Unquoted<'a> {
+        bytes: &'a [u8],
+        quoted: bool,
+    }
+
+    impl Iterator for Unquoted<'_> {
+        type Item = u8;
+
+        fn next(&mut self) -> Option<u8> {
+            if !self.quoted {
+                let (&b, tail) = self.bytes.split_first()?;
+                self.bytes = tail;
+                return Some(b)
+            }
+
+            match self.bytes {
+                [b'"', b'"', tail @ ..] => { self.bytes = tail; Some(b'"') },
+                [b'"', ..] | [] => { self.bytes = &[]; None },
+                [b, tail @ ..] => { self.bytes = tail; Some(*b) },
+            }
+        }
+    }
+}
+
+/// An iterator that will return non-overlapping groups of whole records
+/// in a delimited byte buffer, like CSV or TSV, that share the same fields.
+///
+/// The records are terminated by `"\n"` or `"\r\n"` and their fields are separated
+/// by the delimiter, two records are in the same group when the bytes of the selected
+/// fields are equal, a missing field being only equal to another missing field.
+///
+/// It uses *linear search* by default and can use *exponential search* over the
+/// record starts when the buffer is known to be sorted by the selected fields.
+pub struct DelimitedGroupBy<'a, I> {
+    buffer: &'a [u8],
+    delimiter: u8,
+    fields: I,
+    sorted: bool,
+    #[cfg(feature = "quoted-fields")]
+    quoted: bool,
+}
+
+impl<'a, I> DelimitedGroupBy<'a, I>
+where I: AsRef<[usize]>,
+{
+    pub fn new(buffer: &'a [u8], delimiter: u8, fields: I) -> Self {
+        DelimitedGroupBy {
+            buffer,
+            delimiter,
+            fields,
+            sorted: false,
+            #[cfg(feature = "quoted-fields")]
+            quoted: false,
+        }
+    }
+
+    /// Uses *exponential search* over the record starts to find the end of the groups,
+    /// the records must be sorted, or at least grouped, by the selected fields.
+    ///
+    /// The search is always linear for quoted fields, as a record start
+    /// cannot be found without reading the buffer from its start.
+    pub fn sorted(mut self) -> Self {
+        self.sorted = true;
+        self
+    }
+
+    /// Reads the fields as defined by RFC 4180: the fields can be surrounded by double quotes
+    /// to contain delimiters, line terminators and escaped double quotes (`""`), the fields are
+    /// compared without their surrounding double quotes and with their double quotes unescaped.
+    ///
+    /// This method is only available with the `quoted-fields` feature.
+    #[cfg(feature = "quoted-fields")]
+    pub fn quoted(mut self) -> Self {
+        self.quoted = true;
+        self
+    }
+
+    /// Returns the remainder of the original buffer that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[u8] {
+        self.buffer
+    }
+
+    #[cfg(not(feature = "quoted-fields"))]
+    fn is_quoted(&self) -> bool {
+        false
+    }
+
+    #[cfg(feature = "quoted-fields")]
+    fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    fn record_end(&self, start: usize) -> usize {
+        #[cfg(feature = "quoted-fields")]
+        {
+            if self.quoted { return quoted::record_end(self.buffer, start) }
+        }
+        record_end(self.buffer, start)
+    }
+
+    fn same_fields(&self, a: &[u8], b: &[u8]) -> bool {
+        let delimiter = self.delimiter;
+        self.fields.as_ref().iter().all(|&index| {
+            #[cfg(feature = "quoted-fields")]
+            {
+                if self.quoted {
+                    return match (quoted::field(a, delimiter, index), quoted::field(b, delimiter, index)) {
+                        (Some(a), Some(b)) => quoted::unquoted(a).eq(quoted::unquoted(b)),
+                        (a, b) => a.is_none() && b.is_none(),
+                    }
+                }
+            }
+            field(a, delimiter, index) == field(b, delimiter, index)
+        })
+    }
+}
+
+impl<'a, I> Iterator for DelimitedGroupBy<'a, I>
+where I: AsRef<[usize]>,
+{
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() { return None }
+
+        let buffer = self.buffer;
+        let first_end = self.record_end(0);
+        let first = &buffer[..first_end];
+
+        let end = if self.sorted && !self.is_quoted() {
+            // the records of the group are contiguous, therefore a byte is in a record of the group
+            // until the first byte of the first record of another group
+            let tail = &buffer[first_end..];
+            let predicate = |b: &u8| {
+                let index = unsafe { offset_from(b as *const u8, buffer.as_ptr()) };
+                let start = record_start(buffer, index);
+                let record = &buffer[start..record_end(buffer, start)];
+                if self.same_fields(first, record) { Ordering::Less } else { Ordering::Greater }
+            };
+            first_end + exponential_search_by(tail, predicate).unwrap_err()
+        } else {
+            let mut end = first_end;
+            while end != buffer.len() {
+                let next_end = self.record_end(end);
+                if !self.same_fields(first, &buffer[end..next_end]) { break }
+                end = next_end;
+            }
+            end
+        };
+
+        let (head, tail) = mem::take(&mut self.buffer).split_at(end);
+        self.buffer = tail;
+        Some(head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.buffer.is_empty() { (0, Some(0)) } else { (1, Some(self.buffer.len())) }
+    }
+}
+
+impl<'a, I> FusedIterator for DelimitedGroupBy<'a, I>
+where I: AsRef<[usize]>,
+{ }
+
+impl<'a, I: fmt::Debug> fmt::Debug for DelimitedGroupBy<'a, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DelimitedGroupBy")
+            .field("buffer", &self.buffer)
+            .field("delimiter", &self.delimiter)
+            .field("fields", &self.fields)
+            .field("sorted", &self.sorted)
+            .finish()
+    }
+}
+
+/// Groups the whole records of a delimited byte buffer, like CSV or TSV,
+/// that share the same bytes at the given field index.
+///
+/// ```rust
+/// use slice_group_by::delimited_group_by_field;
+///
+/// let tsv = b"1\tfr\tparis\n2\tfr\tlyon\n3\tjp\ttokyo\n";
+///
+/// let mut iter = delimited_group_by_field(tsv, b'\t', 1);
+///
+/// assert_eq!(iter.next(), Some(&b"1\tfr\tparis\n2\tfr\tlyon\n"[..]));
+/// assert_eq!(iter.next(), Some(&b"3\tjp\ttokyo\n"[..]));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn delimited_group_by_field(buffer: &[u8], delimiter: u8, field: usize) -> DelimitedGroupBy<'_, [usize; 1]> {
+    DelimitedGroupBy::new(buffer, delimiter, [field])
+}
+
+/// Groups the whole records of a delimited byte buffer, like CSV or TSV,
+/// that share the same bytes at all the given field indexes.
+///
+/// ```rust
+/// use slice_group_by::delimited_group_by_fields;
+///
+/// let csv = b"fr,paris,1\nfr,paris,2\nfr,lyon,3\n";
+///
+/// let mut iter = delimited_group_by_fields(csv, b',', &[0, 1]).sorted();
+///
+/// assert_eq!(iter.next(), Some(&b"fr,paris,1\nfr,paris,2\n"[..]));
+/// assert_eq!(iter.next(), Some(&b"fr,lyon,3\n"[..]));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn delimited_group_by_fields<'a, 'f>(
+    buffer: &'a [u8],
+    delimiter: u8,
+    fields: &'f [usize],
+) -> DelimitedGroupBy<'a, &'f [usize]>
+{
+    DelimitedGroupBy::new(buffer, delimiter, fields)
+}
@@ -0,0 +1,82 @@
+mod delimited_group;
+
+pub use self::delimited_group::{DelimitedGroupBy, delimited_group_by_field, delimited_group_by_fields};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_by_field() {
+        let tsv = b"a\t1\tx\nb\t1\ty\r\nc\t2\nd\t2\te\ne\t1\tz";
+
+        let groups: Vec<_> = delimited_group_by_field(tsv, b'\t', 1).collect();
+        assert_eq!(groups, vec![
+            &b"a\t1\tx\nb\t1\ty\r\n"[..],
+            &b"c\t2\nd\t2\te\n"[..],
+            &b"e\t1\tz"[..],
+        ]);
+
+        // the missing fields are equal
+        let groups: Vec<_> = delimited_group_by_field(tsv, b'\t', 2).collect();
+        assert_eq!(groups, vec![
+            &b"a\t1\tx\n"[..],
+            &b"b\t1\ty\r\n"[..],
+            &b"c\t2\n"[..],
+            &b"d\t2\te\n"[..],
+            &b"e\t1\tz"[..],
+        ]);
+    }
+
+    #[test]
+    fn sorted_is_linear_equivalent() {
+        let mut csv = Vec::new();
+        for i in 0..200 {
+            let key = i / 7;
+            let padding = "x".repeat(i % 13);
+            csv.extend_from_slice(format!("{},{},{}\n", i % 3, key, padding).as_bytes());
+        }
+
+        let linear: Vec<_> = delimited_group_by_field(&csv, b',', 1).collect();
+        let sorted: Vec<_> = delimited_group_by_field(&csv, b',', 1).sorted().collect();
+        assert_eq!(linear.len(), 29);
+        assert_eq!(linear, sorted);
+
+        let linear: Vec<_> = delimited_group_by_fields(&csv, b',', &[1, 0]).collect();
+        let sorted: Vec<_> = delimited_group_by_fields(&csv, b',', &[1, 0]).sorted().collect();
+        assert_eq!(linear, sorted);
+    }
+
+    #[test]
+    fn group_by_fields() {
+        let csv = b"fr,paris,1\nfr,paris,2\nfr,lyon,3\nit,lyon,4\n\n\n";
+
+        let mut iter = delimited_group_by_fields(csv, b',', &[0, 1]).sorted();
+        assert_eq!(iter.next(), Some(&b"fr,paris,1\nfr,paris,2\n"[..]));
+        assert_eq!(iter.next(), Some(&b"fr,lyon,3\n"[..]));
+        assert_eq!(iter.remainder(), b"it,lyon,4\n\n\n");
+        assert_eq!(iter.next(), Some(&b"it,lyon,4\n"[..]));
+        assert_eq!(iter.next(), Some(&b"\n\n"[..]));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(delimited_group_by_field(b"", b',', 0).next(), None);
+    }
+
+    #[cfg(feature = "quoted-fields")]
+    #[test]
+    fn quoted_fields() {
+        let csv = b"\"a,b\",1\n\"a,b\",\"multi\nline\"\na\"\"b,2\n\"a\"\"b\",3\n";
+
+        let groups: Vec<_> = delimited_group_by_field(csv, b',', 0).quoted().collect();
+        assert_eq!(groups, vec![
+            &b"\"a,b\",1\n\"a,b\",\"multi\nline\"\n"[..],
+            &b"a\"\"b,2\n"[..],
+            &b"\"a\"\"b\",3\n"[..],
+        ]);
+
+        let csv = b"\"x\",1\nx,2\n\"\"\"\",3\n\"\"\"\",4";
+
+        let groups: Vec<_> = delimited_group_by_field(csv, b',', 0).quoted().sorted().collect();
+        assert_eq!(groups, vec![&b"\"x\",1\nx,2\n"[..], &b"\"\"\"\",3\n\"\"\"\",4"[..]]);
+    }
+}
@@ -78,6 +78,11 @@ macro_rules! exponential_group_by_key {
         where F: FnMut(&T) -> K,
               K: PartialEq
         { }
+
+        unsafe impl<'a, T: 'a, F, K> crate::GroupIterator for $name<'a, T, F>
+        where F: FnMut(&T) -> K,
+              K: PartialEq
+        { }
     }
 }
 
@@ -0,0 +1,119 @@
+use crate::{SearchMode, LinearGroupBy, BinaryGroupBy, ExponentialGroupBy};
+
+/// Writes the start indices of the groups of the slice into the buffer,
+/// the groups being defined by the predicate and searched using the given [`SearchMode`].
+///
+/// The writing starts with the group that begins at the `cursor` index,
+/// the `cursor` is then moved to the start of the first group that did not fit in the buffer,
+/// it is equal to the length of the slice when all the boundaries have been written.
+/// Returns the number of boundaries written.
+///
+/// This function does not allocate and is available without the `std` feature.
+///
+/// [`SearchMode`]: enum.SearchMode.html
+///
+/// # Panics
+///
+/// Panics if `cursor` is greater than the length of the slice.
+///
+/// ```rust
+/// use slice_group_by::{group_boundaries_into, SearchMode};
+///
+/// let slice = &[1, 1, 2, 3, 3, 3, 4, 5, 5];
+///
+/// let mut cursor = 0;
+/// let mut buffer = [0; 3];
+///
+/// let count = group_boundaries_into(slice, SearchMode::Linear, |a, b| a == b, &mut cursor, &mut buffer);
+/// assert_eq!(&buffer[..count], &[0, 2, 3]);
+/// assert_eq!(cursor, 6);
+///
+/// let count = group_boundaries_into(slice, SearchMode::Linear, |a, b| a == b, &mut cursor, &mut buffer);
+/// assert_eq!(&buffer[..count], &[6, 7]);
+/// assert_eq!(cursor, slice.len());
+/// ```
+pub fn group_boundaries_into<T, P>(
+    slice: &[T],
+    mode: SearchMode,
+    predicate: P,
+    cursor: &mut usize,
+    buffer: &mut [usize],
+) -> usize
+where P: FnMut(&T, &T) -> bool,
+{
+    let remaining = &slice[*cursor..];
+    match mode {
+        SearchMode::Linear => fill_boundaries(LinearGroupBy::new(remaining, predicate), cursor, buffer),
+        SearchMode::Binary => fill_boundaries(BinaryGroupBy::new(remaining, predicate), cursor, buffer),
+        SearchMode::Exponential => fill_boundaries(ExponentialGroupBy::new(remaining, predicate), cursor, buffer),
+    }
+}
+
+fn fill_boundaries<'a, T: 'a, I>(groups: I, cursor: &mut usize, buffer: &mut [usize]) -> usize
+where I: Iterator<Item = &'a [T]>,
+{
+    let mut count = 0;
+    // the buffer comes first to not consume a group that does not fit
+    for (boundary, group) in buffer.iter_mut().zip(groups) {
+        *boundary = *cursor;
+        *cursor += group.len();
+        count += 1;
+    }
+    count
+}
+
+/// Sets one bit for each group start of the slice in the bitmap, the groups being defined
+/// by the predicate and searched using the given [`SearchMode`].
+///
+/// The start of a group at index `i` is represented by the bit `i % 64` of the word `i / 64`,
+/// the words covering the slice are cleared first. Returns the number of groups.
+///
+/// This function does not allocate and is available without the `std` feature.
+///
+/// [`SearchMode`]: enum.SearchMode.html
+///
+/// # Panics
+///
+/// Panics if the bitmap has less than one bit for each element of the slice.
+///
+/// ```rust
+/// use slice_group_by::{group_boundaries_bitmap, SearchMode};
+///
+/// let slice = &[1, 1, 2, 3, 3, 3, 4, 5, 5];
+///
+/// let mut bitmap = [0u64; 1];
+///
+/// let count = group_boundaries_bitmap(slice, SearchMode::Binary, |a, b| a == b, &mut bitmap);
+/// assert_eq!(count, 5);
+/// assert_eq!(bitmap[0], 0b0_1100_1101);
+/// ```
+pub fn group_boundaries_bitmap<T, P>(slice: &[T], mode: SearchMode, predicate: P, bitmap: &mut [u64]) -> usize
+where P: FnMut(&T, &T) -> bool,
+{
+    // usize::div_ceil requires Rust 1.73
+    #[allow(clippy::manual_div_ceil)]
+    let words = (slice.len() + 63) / 64;
+    assert!(bitmap.len() >= words, "bitmap is too small for the slice");
+
+    let bitmap = &mut bitmap[..words];
+    bitmap.iter_mut().for_each(|word| *word = 0);
+
+    match mode {
+        SearchMode::Linear => set_boundaries(LinearGroupBy::new(slice, predicate), bitmap),
+        SearchMode::Binary => set_boundaries(BinaryGroupBy::new(slice, predicate), bitmap),
+        SearchMode::Exponential => set_boundaries(ExponentialGroupBy::new(slice, predicate), bitmap),
+    }
+}
+
+fn set_boundaries<'a, T: 'a, I>(groups: I, bitmap: &mut [u64]) -> usize
+where I: Iterator<Item = &'a [T]>,
+{
+    let mut start = 0;
+    let mut count = 0;
+    for group in groups {
+        bitmap[start / 64] |= 1 << (start % 64);
+        start += group.len();
+        count += 1;
+    }
+    count
+}
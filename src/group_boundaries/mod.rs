@@ -0,0 +1,65 @@
+mod group_boundaries;
+
+pub use self::group_boundaries::{group_boundaries_into, group_boundaries_bitmap};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SearchMode;
+
+    #[test]
+    fn boundaries_with_cursor() {
+        let slice = &[1, 1, 1, 2, 3, 3, 4, 4, 4, 4, 5];
+
+        for &mode in &[SearchMode::Linear, SearchMode::Binary, SearchMode::Exponential] {
+            let mut cursor = 0;
+            let mut buffer = [0; 2];
+            let mut boundaries = Vec::new();
+
+            loop {
+                let count = group_boundaries_into(slice, mode, |a, b| a == b, &mut cursor, &mut buffer);
+                if count == 0 { break }
+                boundaries.extend_from_slice(&buffer[..count]);
+            }
+
+            assert_eq!(boundaries, vec![0, 3, 4, 6, 10]);
+            assert_eq!(cursor, slice.len());
+        }
+    }
+
+    #[test]
+    fn boundaries_empty() {
+        let slice: &[i32] = &[];
+
+        let mut cursor = 0;
+        let mut buffer = [0; 2];
+        assert_eq!(group_boundaries_into(slice, SearchMode::Linear, |a, b| a == b, &mut cursor, &mut buffer), 0);
+        assert_eq!(cursor, 0);
+
+        let mut bitmap = [];
+        assert_eq!(group_boundaries_bitmap(slice, SearchMode::Binary, |a, b| a == b, &mut bitmap), 0);
+    }
+
+    #[test]
+    fn bitmap_multiple_words() {
+        let mut slice = [0; 130];
+        slice[64..].iter_mut().for_each(|x| *x = 1);
+        slice[129] = 2;
+
+        for &mode in &[SearchMode::Linear, SearchMode::Binary, SearchMode::Exponential] {
+            let mut bitmap = [u64::MAX; 4];
+
+            let count = group_boundaries_bitmap(&slice, mode, |a, b| a == b, &mut bitmap);
+            assert_eq!(count, 3);
+            assert_eq!(bitmap, [1, 1, 0b10, u64::MAX]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn bitmap_too_small() {
+        let slice = [0; 65];
+        let mut bitmap = [0u64; 1];
+        group_boundaries_bitmap(&slice, SearchMode::Linear, |a, b| a == b, &mut bitmap);
+    }
+}
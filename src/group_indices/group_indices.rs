@@ -0,0 +1,239 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::fmt;
+use std::slice;
+use crate::ExponentialGroupByKey;
+
+/// The groups of an unsorted slice, represented by a sorted permutation
+/// of the indices of its elements and the ranges of the groups in this permutation.
+///
+/// The elements of a group are in the order of the slice.
+pub struct GroupedIndices<'a, T: 'a, F> {
+    slice: &'a [T],
+    func: F,
+    indices: Vec<usize>,
+    ranges: Vec<Range<usize>>,
+}
+
+impl<'a, T: 'a, F> GroupedIndices<'a, T, F> {
+    /// Returns the permutation of the indices of the slice, sorted by key.
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the ranges of the groups in the permutation.
+    pub fn ranges(&self) -> &[Range<usize>] {
+        &self.ranges
+    }
+
+    /// Returns the number of groups.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if there is no group.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns an iterator over the elements of the group at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of the groups.
+    pub fn group(&self, index: usize) -> GroupElements<'_, 'a, T> {
+        let indices = &self.indices[self.ranges[index].clone()];
+        GroupElements { slice: self.slice, indices: indices.iter() }
+    }
+
+    /// Returns an iterator over the groups along with their key.
+    pub fn iter(&self) -> GroupedIndicesIter<'_, 'a, T, F> {
+        GroupedIndicesIter { grouped: self, ranges: self.ranges.iter() }
+    }
+}
+
+impl<'b, 'a, T: 'a, F, K> IntoIterator for &'b GroupedIndices<'a, T, F>
+where F: Fn(&T) -> K,
+{
+    type Item = (K, GroupElements<'b, 'a, T>);
+    type IntoIter = GroupedIndicesIter<'b, 'a, T, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for GroupedIndices<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupedIndices")
+            .field("slice", &self.slice)
+            .field("indices", &self.indices)
+            .field("ranges", &self.ranges)
+            .finish()
+    }
+}
+
+/// An iterator over the groups of a [`GroupedIndices`] along with their key.
+///
+/// [`GroupedIndices`]: struct.GroupedIndices.html
+pub struct GroupedIndicesIter<'b, 'a: 'b, T: 'a, F> {
+    grouped: &'b GroupedIndices<'a, T, F>,
+    ranges: slice::Iter<'b, Range<usize>>,
+}
+
+impl<'b, 'a, T: 'a, F> GroupedIndicesIter<'b, 'a, T, F> {
+    fn group(&self, range: &Range<usize>) -> GroupElements<'b, 'a, T> {
+        let indices = &self.grouped.indices[range.clone()];
+        GroupElements { slice: self.grouped.slice, indices: indices.iter() }
+    }
+}
+
+impl<'b, 'a, T: 'a, F, K> Iterator for GroupedIndicesIter<'b, 'a, T, F>
+where F: Fn(&T) -> K,
+{
+    type Item = (K, GroupElements<'b, 'a, T>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.ranges.next()?;
+        let key = (self.grouped.func)(&self.grouped.slice[self.grouped.indices[range.start]]);
+        Some((key, self.group(range)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'b, 'a, T: 'a, F, K> DoubleEndedIterator for GroupedIndicesIter<'b, 'a, T, F>
+where F: Fn(&T) -> K,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let range = self.ranges.next_back()?;
+        let key = (self.grouped.func)(&self.grouped.slice[self.grouped.indices[range.start]]);
+        Some((key, self.group(range)))
+    }
+}
+
+impl<'b, 'a, T: 'a, F, K> ExactSizeIterator for GroupedIndicesIter<'b, 'a, T, F>
+where F: Fn(&T) -> K,
+{ }
+
+impl<'b, 'a, T: 'a, F, K> FusedIterator for GroupedIndicesIter<'b, 'a, T, F>
+where F: Fn(&T) -> K,
+{ }
+
+impl<'b, 'a, T: 'a, F> fmt::Debug for GroupedIndicesIter<'b, 'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupedIndicesIter")
+            .field("ranges", &self.ranges.as_slice())
+            .finish()
+    }
+}
+
+/// An iterator over the elements of a group of a [`GroupedIndices`].
+///
+/// [`GroupedIndices`]: struct.GroupedIndices.html
+pub struct GroupElements<'b, 'a, T: 'a> {
+    slice: &'a [T],
+    indices: slice::Iter<'b, usize>,
+}
+
+impl<'b, 'a, T: 'a> GroupElements<'b, 'a, T> {
+    /// Returns the indices in the slice of the remaining elements.
+    pub fn indices(&self) -> &'b [usize] {
+        self.indices.as_slice()
+    }
+}
+
+impl<'b, 'a, T: 'a> Iterator for GroupElements<'b, 'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.indices.next().map(|&i| &self.slice[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'b, 'a, T: 'a> DoubleEndedIterator for GroupElements<'b, 'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.indices.next_back().map(|&i| &self.slice[i])
+    }
+}
+
+impl<'b, 'a, T: 'a> ExactSizeIterator for GroupElements<'b, 'a, T> { }
+
+impl<'b, 'a, T: 'a> FusedIterator for GroupElements<'b, 'a, T> { }
+
+impl<'b, 'a, T: 'a> Clone for GroupElements<'b, 'a, T> {
+    fn clone(&self) -> Self {
+        GroupElements { slice: self.slice, indices: self.indices.clone() }
+    }
+}
+
+impl<'b, 'a, T: 'a + fmt::Debug> fmt::Debug for GroupElements<'b, 'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// Groups the elements of an unsorted slice by key without moving them,
+/// by sorting a permutation of their indices.
+///
+/// The ranges of the groups in the permutation are found using an exponential search.
+///
+/// ```rust
+/// use slice_group_by::group_indices_by_key;
+///
+/// let words = &["apple", "bob", "avocado", "cherry", "banana"];
+///
+/// let grouped = group_indices_by_key(words, |w| w.as_bytes()[0]);
+///
+/// assert_eq!(grouped.indices(), &[0, 2, 1, 4, 3]);
+///
+/// let mut iter = grouped.iter();
+///
+/// let (key, group) = iter.next().unwrap();
+/// assert_eq!(key, b'a');
+/// assert_eq!(group.collect::<Vec<_>>(), vec![&"apple", &"avocado"]);
+///
+/// let (key, group) = iter.next().unwrap();
+/// assert_eq!(key, b'b');
+/// assert_eq!(group.collect::<Vec<_>>(), vec![&"bob", &"banana"]);
+///
+/// let (key, group) = iter.next().unwrap();
+/// assert_eq!(key, b'c');
+/// assert_eq!(group.len(), 1);
+///
+/// assert!(iter.next().is_none());
+/// ```
+pub fn group_indices_by_key<T, F, K>(slice: &[T], func: F) -> GroupedIndices<'_, T, F>
+where F: Fn(&T) -> K,
+      K: Ord,
+{
+    let mut indices: Vec<usize> = (0..slice.len()).collect();
+    indices.sort_by_key(|&i| func(&slice[i]));
+
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    for group in ExponentialGroupByKey::new(&indices, |&i: &usize| func(&slice[i])) {
+        ranges.push(start..start + group.len());
+        start += group.len();
+    }
+
+    GroupedIndices { slice, func, indices, ranges }
+}
@@ -0,0 +1,51 @@
+mod group_indices;
+
+pub use self::group_indices::{
+    group_indices_by_key,
+    GroupedIndices,
+    GroupedIndicesIter,
+    GroupElements,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_groups() {
+        let slice = &[(3, 'a'), (1, 'b'), (3, 'c'), (2, 'd'), (1, 'e'), (3, 'f')];
+
+        let grouped = group_indices_by_key(slice, |x| x.0);
+
+        assert_eq!(grouped.len(), 3);
+        assert_eq!(grouped.indices(), &[1, 4, 3, 0, 2, 5]);
+        assert_eq!(grouped.ranges(), &[0..2, 2..3, 3..6]);
+
+        let groups: Vec<_> = grouped.iter().map(|(k, g)| (k, g.map(|x| x.1).collect::<String>())).collect();
+        assert_eq!(groups, vec![(1, "be".to_string()), (2, "d".to_string()), (3, "acf".to_string())]);
+
+        let group = grouped.group(2);
+        assert_eq!(group.indices(), &[0, 2, 5]);
+        assert_eq!(group.rev().map(|x| x.1).collect::<String>(), "fca");
+    }
+
+    #[test]
+    fn rev_groups() {
+        let slice = &[5, 1, 5, 1];
+
+        let grouped = group_indices_by_key(slice, |x| *x);
+
+        let keys: Vec<_> = (&grouped).into_iter().rev().map(|(k, g)| (k, g.len())).collect();
+        assert_eq!(keys, vec![(5, 2), (1, 2)]);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let slice: &[i32] = &[];
+
+        let grouped = group_indices_by_key(slice, |x| *x);
+
+        assert!(grouped.is_empty());
+        assert!(grouped.iter().next().is_none());
+    }
+}
@@ -0,0 +1,315 @@
+use std::iter::FusedIterator;
+use std::fmt;
+use crate::{Group, GroupIterator, GroupSlice};
+
+/// The policy applied by the [`GroupBatches`] iterator to the groups
+/// that are heavier than the maximum weight of a batch.
+///
+/// [`GroupBatches`]: struct.GroupBatches.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Oversized {
+    /// The oversized group is returned alone, in a batch heavier than the maximum weight.
+    Alone,
+    /// The oversized group is split into batches that respect the maximum weight,
+    /// an element heavier than the maximum weight is returned alone.
+    Split,
+}
+
+/// The error returned by the [`TryGroupBatches`] iterator for the groups
+/// that are heavier than the maximum weight of a batch.
+///
+/// [`TryGroupBatches`]: struct.TryGroupBatches.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OversizedGroup<G>(pub G);
+
+impl<G> fmt::Display for OversizedGroup<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("group is heavier than the maximum weight of a batch")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<G: fmt::Debug> std::error::Error for OversizedGroup<G> { }
+
+/// The weight function that counts the elements of the groups.
+pub(crate) type UnitWeight<G> = fn(&<G as GroupSlice>::Elem) -> usize;
+
+enum Batch<G> {
+    Fit(G),
+    Oversized(G),
+}
+
+/// An iterator that joins contiguous whole groups into batches that are
+/// not heavier than a maximum weight.
+///
+/// The weight of a group is the sum of the weights of its elements.
+/// The chunks of a split oversized group are returned as their own batches.
+/// Batches returned from the back are filled starting from the end of the slice,
+/// therefore they are not necessarily the same as the ones returned from the front.
+pub struct GroupBatches<I: Iterator, W> {
+    iter: I,
+    max_weight: usize,
+    weight: W,
+    oversized: Oversized,
+    front: Option<(I::Item, usize)>,
+    back: Option<(I::Item, usize)>,
+    front_chunk: Option<I::Item>,
+    back_chunk: Option<I::Item>,
+}
+
+impl<I, W> GroupBatches<I, W>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{
+    pub fn new(iter: I, max_weight: usize, weight: W) -> Self {
+        GroupBatches { iter, max_weight, weight, oversized: Oversized::Alone, front: None, back: None, front_chunk: None, back_chunk: None }
+    }
+
+    /// Defines the policy to apply to the groups heavier than the maximum weight,
+    /// the default policy is [`Oversized::Alone`].
+    ///
+    /// [`Oversized::Alone`]: enum.Oversized.html#variant.Alone
+    pub fn oversized(mut self, policy: Oversized) -> Self {
+        self.oversized = policy;
+        self
+    }
+
+    /// Returns an iterator that reports the groups heavier than
+    /// the maximum weight as errors.
+    pub fn try_oversized(self) -> TryGroupBatches<I, W> {
+        TryGroupBatches(self)
+    }
+
+    fn weigh(&mut self, group: I::Item) -> (I::Item, usize) {
+        // a group heavier than the maximum weight doesn't need its exact weight
+        let weight = group.as_slice().iter().map(&mut self.weight).fold(0, usize::saturating_add);
+        (group, weight)
+    }
+
+    fn next_front_group(&mut self) -> Option<(I::Item, usize)> {
+        if let Some(group) = self.front.take() { return Some(group) }
+        match self.iter.next() {
+            Some(group) => Some(self.weigh(group)),
+            None => self.back.take(),
+        }
+    }
+
+    fn next_back_group(&mut self) -> Option<(I::Item, usize)> {
+        if let Some(group) = self.back.take() { return Some(group) }
+        match self.iter.next_back() {
+            Some(group) => Some(self.weigh(group)),
+            None => self.front.take(),
+        }
+    }
+
+    fn next_front_batch(&mut self) -> Option<Batch<I::Item>> {
+        let (mut batch, mut total) = self.next_front_group()?;
+        if total > self.max_weight { return Some(Batch::Oversized(batch)) }
+
+        while let Some((group, weight)) = self.next_front_group() {
+            if weight > self.max_weight - total {
+                self.front = Some((group, weight));
+                break
+            }
+            // the groups are returned by a GroupIterator, so contiguous
+            batch = unsafe { batch.join(group) };
+            total += weight;
+        }
+
+        Some(Batch::Fit(batch))
+    }
+
+    fn next_back_batch(&mut self) -> Option<Batch<I::Item>> {
+        let (mut batch, mut total) = self.next_back_group()?;
+        if total > self.max_weight { return Some(Batch::Oversized(batch)) }
+
+        while let Some((group, weight)) = self.next_back_group() {
+            if weight > self.max_weight - total {
+                self.back = Some((group, weight));
+                break
+            }
+            // the groups are returned by a GroupIterator, so contiguous
+            batch = unsafe { group.join(batch) };
+            total += weight;
+        }
+
+        Some(Batch::Fit(batch))
+    }
+
+    fn split_front(&mut self, group: I::Item) -> I::Item {
+        let mut total = 0;
+        let mut len = 0;
+        for elem in group.as_slice() {
+            let weight = (self.weight)(elem);
+            if len != 0 && weight > self.max_weight.saturating_sub(total) { break }
+            total += weight;
+            len += 1;
+        }
+
+        let (head, tail) = group.split_at(len);
+        if !tail.is_empty() {
+            self.front_chunk = Some(tail);
+        }
+        head
+    }
+
+    fn split_back(&mut self, group: I::Item) -> I::Item {
+        let mut total = 0;
+        let mut len = 0;
+        for elem in group.as_slice().iter().rev() {
+            let weight = (self.weight)(elem);
+            if len != 0 && weight > self.max_weight.saturating_sub(total) { break }
+            total += weight;
+            len += 1;
+        }
+
+        let mid = group.len() - len;
+        let (head, tail) = group.split_at(mid);
+        if !head.is_empty() {
+            self.back_chunk = Some(head);
+        }
+        tail
+    }
+}
+
+impl<I, W> Iterator for GroupBatches<I, W>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // the remaining chunks of a split group are never joined with other groups
+        if let Some(chunk) = self.front_chunk.take() {
+            return Some(self.split_front(chunk))
+        }
+
+        match self.next_front_batch() {
+            Some(Batch::Fit(batch)) => Some(batch),
+            Some(Batch::Oversized(group)) => match self.oversized {
+                Oversized::Alone => Some(group),
+                Oversized::Split => Some(self.split_front(group)),
+            },
+            None => self.back_chunk.take().map(|chunk| self.split_front(chunk)),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.front.is_some() as usize + self.back.is_some() as usize;
+        let chunks = self.front_chunk.is_some() as usize + self.back_chunk.is_some() as usize;
+        let (low, high) = self.iter.size_hint();
+        let low = if low + pending + chunks == 0 { 0 } else { 1 };
+        match self.oversized {
+            Oversized::Alone => (low, high.and_then(|h| h.checked_add(pending))),
+            Oversized::Split => (low, None),
+        }
+    }
+}
+
+impl<I, W> DoubleEndedIterator for GroupBatches<I, W>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // the remaining chunks of a split group are never joined with other groups
+        if let Some(chunk) = self.back_chunk.take() {
+            return Some(self.split_back(chunk))
+        }
+
+        match self.next_back_batch() {
+            Some(Batch::Fit(batch)) => Some(batch),
+            Some(Batch::Oversized(group)) => match self.oversized {
+                Oversized::Alone => Some(group),
+                Oversized::Split => Some(self.split_back(group)),
+            },
+            None => self.front_chunk.take().map(|chunk| self.split_back(chunk)),
+        }
+    }
+}
+
+impl<I, W> FusedIterator for GroupBatches<I, W>
+where I: GroupIterator + FusedIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{ }
+
+unsafe impl<I, W> GroupIterator for GroupBatches<I, W>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{ }
+
+impl<I, W> fmt::Debug for GroupBatches<I, W>
+where I: Iterator + fmt::Debug,
+      I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupBatches")
+            .field("iter", &self.iter)
+            .field("max_weight", &self.max_weight)
+            .field("oversized", &self.oversized)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .field("front_chunk", &self.front_chunk)
+            .field("back_chunk", &self.back_chunk)
+            .finish()
+    }
+}
+
+/// An iterator that joins contiguous whole groups into batches that are
+/// not heavier than a maximum weight, reporting the heavier groups as errors.
+pub struct TryGroupBatches<I: Iterator, W>(GroupBatches<I, W>);
+
+impl<I, W> Iterator for TryGroupBatches<I, W>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{
+    type Item = Result<I::Item, OversizedGroup<I::Item>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0.next_front_batch()? {
+            Batch::Fit(batch) => Some(Ok(batch)),
+            Batch::Oversized(group) => Some(Err(OversizedGroup(group))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I, W> DoubleEndedIterator for TryGroupBatches<I, W>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.0.next_back_batch()? {
+            Batch::Fit(batch) => Some(Ok(batch)),
+            Batch::Oversized(group) => Some(Err(OversizedGroup(group))),
+        }
+    }
+}
+
+impl<I, W> FusedIterator for TryGroupBatches<I, W>
+where I: GroupIterator + FusedIterator,
+      I::Item: GroupSlice,
+      W: FnMut(&<I::Item as GroupSlice>::Elem) -> usize,
+{ }
+
+impl<I, W> fmt::Debug for TryGroupBatches<I, W>
+where I: Iterator + fmt::Debug,
+      I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("TryGroupBatches").field(&self.0).finish()
+    }
+}
@@ -0,0 +1,147 @@
+use std::iter::FusedIterator;
+use std::fmt;
+use crate::{Group, GroupIterator};
+
+/// Indicates whether a chunk returned by the [`GroupMaxLen`] iterator
+/// is the start or a continuation of a group.
+///
+/// [`GroupMaxLen`]: struct.GroupMaxLen.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupPart {
+    /// The chunk is the start of a group, it can also be the whole group.
+    Start,
+    /// The chunk follows another chunk of the same group.
+    Continuation,
+}
+
+impl GroupPart {
+    fn new(is_start: bool) -> GroupPart {
+        if is_start { GroupPart::Start } else { GroupPart::Continuation }
+    }
+}
+
+/// An iterator that splits the groups longer than a maximum length into
+/// consecutive chunks of at most this length.
+///
+/// The chunks of a group always start at a multiple of the maximum length
+/// from the start of the group, therefore the chunks returned from the back
+/// are the same as the ones returned from the front.
+///
+/// The length of a group is only computed once, every chunk is then split
+/// from the remainder of the group by walking at most `max_len` elements.
+pub struct GroupMaxLen<I: Iterator> {
+    iter: I,
+    max_len: usize,
+    front: Option<Pending<I::Item>>,
+    back: Option<Pending<I::Item>>,
+}
+
+/// The remainder of a group along with its length and
+/// whether it is the start of the group.
+#[derive(Debug)]
+struct Pending<G> {
+    group: G,
+    len: usize,
+    is_start: bool,
+}
+
+impl<I> GroupMaxLen<I>
+where I: GroupIterator,
+      I::Item: Group,
+{
+    /// Creates a new iterator of chunks of at most `max_len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    pub fn new(iter: I, max_len: usize) -> Self {
+        assert!(max_len != 0, "max_len cannot be zero");
+        GroupMaxLen { iter, max_len, front: None, back: None }
+    }
+
+    fn next_front_group(&mut self) -> Option<Pending<I::Item>> {
+        if let Some(pending) = self.front.take() { return Some(pending) }
+        match self.iter.next() {
+            Some(group) => Some(Pending { len: group.len(), group, is_start: true }),
+            None => self.back.take(),
+        }
+    }
+
+    fn next_back_group(&mut self) -> Option<Pending<I::Item>> {
+        if let Some(pending) = self.back.take() { return Some(pending) }
+        match self.iter.next_back() {
+            Some(group) => Some(Pending { len: group.len(), group, is_start: true }),
+            None => self.front.take(),
+        }
+    }
+}
+
+impl<I> Iterator for GroupMaxLen<I>
+where I: GroupIterator,
+      I::Item: Group,
+{
+    type Item = (GroupPart, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Pending { group, len, is_start } = self.next_front_group()?;
+
+        if len <= self.max_len {
+            return Some((GroupPart::new(is_start), group))
+        }
+
+        let (head, tail) = group.split_at(self.max_len);
+        self.front = Some(Pending { group: tail, len: len - self.max_len, is_start: false });
+        Some((GroupPart::new(is_start), head))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.front.is_some() as usize + self.back.is_some() as usize;
+        let (low, _) = self.iter.size_hint();
+        (low + pending, None)
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<I> DoubleEndedIterator for GroupMaxLen<I>
+where I: GroupIterator,
+      I::Item: Group,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let Pending { group, len, is_start } = self.next_back_group()?;
+
+        // the last chunk starts at the last multiple of
+        // the maximum length that is lower than the group length
+        let mid = (len - 1) / self.max_len * self.max_len;
+        if mid == 0 {
+            return Some((GroupPart::new(is_start), group))
+        }
+
+        let (head, tail) = group.split_last_at(len - mid);
+        self.back = Some(Pending { group: head, len: mid, is_start });
+        Some((GroupPart::Continuation, tail))
+    }
+}
+
+impl<I> FusedIterator for GroupMaxLen<I>
+where I: GroupIterator + FusedIterator,
+      I::Item: Group,
+{ }
+
+impl<I> fmt::Debug for GroupMaxLen<I>
+where I: Iterator + fmt::Debug,
+      I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupMaxLen")
+            .field("iter", &self.iter)
+            .field("max_len", &self.max_len)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
@@ -0,0 +1,164 @@
+use std::iter::FusedIterator;
+use std::fmt;
+
+/// An iterator over overlapping windows of `N` consecutive groups.
+pub struct GroupWindows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I, const N: usize> GroupWindows<I, N>
+where I: Iterator,
+      I::Item: Copy,
+{
+    /// Creates a new iterator of windows of `N` groups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    pub fn new(iter: I) -> Self {
+        assert!(N != 0, "window size cannot be zero");
+        GroupWindows { iter, window: None }
+    }
+}
+
+impl<I, const N: usize> Iterator for GroupWindows<I, N>
+where I: Iterator,
+      I::Item: Copy,
+{
+    type Item = [I::Item; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            Some(window) => {
+                let group = self.iter.next()?;
+                window.rotate_left(1);
+                window[N - 1] = group;
+                Some(*window)
+            },
+            None => {
+                let mut window = [None; N];
+                for slot in &mut window {
+                    *slot = Some(self.iter.next()?);
+                }
+                let window = window.map(Option::unwrap);
+                self.window = Some(window);
+                Some(window)
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.iter.size_hint();
+        let missing = if self.window.is_some() { 0 } else { N - 1 };
+        (low.saturating_sub(missing), high.map(|high| high.saturating_sub(missing)))
+    }
+}
+
+impl<I, const N: usize> FusedIterator for GroupWindows<I, N>
+where I: FusedIterator,
+      I::Item: Copy,
+{ }
+
+impl<I, const N: usize> fmt::Debug for GroupWindows<I, N>
+where I: Iterator + fmt::Debug,
+      I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupWindows")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+/// An iterator over overlapping pairs of consecutive groups.
+pub struct GroupPairs<I: Iterator> {
+    windows: GroupWindows<I, 2>,
+}
+
+impl<I> GroupPairs<I>
+where I: Iterator,
+      I::Item: Copy,
+{
+    pub fn new(iter: I) -> Self {
+        GroupPairs { windows: GroupWindows::new(iter) }
+    }
+}
+
+impl<I> Iterator for GroupPairs<I>
+where I: Iterator,
+      I::Item: Copy,
+{
+    type Item = (I::Item, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.windows.next().map(|[prev, next]| (prev, next))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows.size_hint()
+    }
+}
+
+impl<I> FusedIterator for GroupPairs<I>
+where I: FusedIterator,
+      I::Item: Copy,
+{ }
+
+impl<I> fmt::Debug for GroupPairs<I>
+where I: Iterator + fmt::Debug,
+      I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupPairs")
+            .field("iter", &self.windows.iter)
+            .field("window", &self.windows.window)
+            .finish()
+    }
+}
+
+/// A lending iterator over overlapping pairs of consecutive *mutable* groups,
+/// the previous group is given immutably along with the current one mutably.
+///
+/// It cannot implement `Iterator` as the current group is borrowed again
+/// as the previous group of the next pair, use a `while let` loop instead.
+pub struct GroupPairsMut<'a, T: 'a, I> {
+    iter: I,
+    prev: Option<&'a [T]>,
+    current: Option<&'a mut [T]>,
+}
+
+impl<'a, T: 'a, I> GroupPairsMut<'a, T, I>
+where I: Iterator<Item = &'a mut [T]>,
+{
+    pub fn new(iter: I) -> Self {
+        GroupPairsMut { iter, prev: None, current: None }
+    }
+
+    /// Returns the next pair of groups, the previous one and the current one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<(&[T], &mut [T])> {
+        match self.current.take() {
+            Some(current) => self.prev = Some(current),
+            None if self.prev.is_none() => self.prev = Some(self.iter.next()?),
+            None => (),
+        }
+
+        let prev = self.prev?;
+        let current = self.current.insert(self.iter.next()?);
+        Some((prev, current))
+    }
+}
+
+impl<'a, T: 'a + fmt::Debug, I: fmt::Debug> fmt::Debug for GroupPairsMut<'a, T, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupPairsMut")
+            .field("iter", &self.iter)
+            .field("prev", &self.prev)
+            .field("current", &self.current)
+            .finish()
+    }
+}
@@ -0,0 +1,115 @@
+use std::iter::FusedIterator;
+use std::fmt;
+use crate::{Group, GroupIterator, GroupSlice};
+
+/// An iterator that merges adjacent groups while a predicate
+/// on the two neighbouring groups holds.
+///
+/// The predicate is always given the groups in the order of the slice, the first one being
+/// the group merged so far when iterating forward and the second one when iterating backward.
+/// Therefore the groups returned from the back are not necessarily the same as the ones
+/// returned from the front.
+pub struct MergeAdjacentGroupsBy<I: Iterator, P> {
+    iter: I,
+    predicate: P,
+    front: Option<I::Item>,
+    back: Option<I::Item>,
+}
+
+impl<I, P> MergeAdjacentGroupsBy<I, P>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      P: FnMut(&[<I::Item as GroupSlice>::Elem], &[<I::Item as GroupSlice>::Elem]) -> bool,
+{
+    pub fn new(iter: I, predicate: P) -> Self {
+        MergeAdjacentGroupsBy { iter, predicate, front: None, back: None }
+    }
+
+    fn next_front_group(&mut self) -> Option<I::Item> {
+        if let Some(group) = self.front.take() { return Some(group) }
+        self.iter.next().or_else(|| self.back.take())
+    }
+
+    fn next_back_group(&mut self) -> Option<I::Item> {
+        if let Some(group) = self.back.take() { return Some(group) }
+        self.iter.next_back().or_else(|| self.front.take())
+    }
+}
+
+impl<I, P> Iterator for MergeAdjacentGroupsBy<I, P>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      P: FnMut(&[<I::Item as GroupSlice>::Elem], &[<I::Item as GroupSlice>::Elem]) -> bool,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut merged = self.next_front_group()?;
+
+        while let Some(group) = self.next_front_group() {
+            if !(self.predicate)(merged.as_slice(), group.as_slice()) {
+                self.front = Some(group);
+                break
+            }
+            // the groups are returned by a GroupIterator, so contiguous
+            merged = unsafe { merged.join(group) };
+        }
+
+        Some(merged)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.front.is_some() as usize + self.back.is_some() as usize;
+        let (low, high) = self.iter.size_hint();
+        let low = if low + pending == 0 { 0 } else { 1 };
+        (low, high.map(|high| high + pending))
+    }
+}
+
+impl<I, P> DoubleEndedIterator for MergeAdjacentGroupsBy<I, P>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      P: FnMut(&[<I::Item as GroupSlice>::Elem], &[<I::Item as GroupSlice>::Elem]) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut merged = self.next_back_group()?;
+
+        while let Some(group) = self.next_back_group() {
+            if !(self.predicate)(group.as_slice(), merged.as_slice()) {
+                self.back = Some(group);
+                break
+            }
+            // the groups are returned by a GroupIterator, so contiguous
+            merged = unsafe { group.join(merged) };
+        }
+
+        Some(merged)
+    }
+}
+
+impl<I, P> FusedIterator for MergeAdjacentGroupsBy<I, P>
+where I: GroupIterator + FusedIterator,
+      I::Item: GroupSlice,
+      P: FnMut(&[<I::Item as GroupSlice>::Elem], &[<I::Item as GroupSlice>::Elem]) -> bool,
+{ }
+
+unsafe impl<I, P> GroupIterator for MergeAdjacentGroupsBy<I, P>
+where I: GroupIterator,
+      I::Item: GroupSlice,
+      P: FnMut(&[<I::Item as GroupSlice>::Elem], &[<I::Item as GroupSlice>::Elem]) -> bool,
+{ }
+
+impl<I, P> fmt::Debug for MergeAdjacentGroupsBy<I, P>
+where I: Iterator + fmt::Debug,
+      I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MergeAdjacentGroupsBy")
+            .field("iter", &self.iter)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
@@ -0,0 +1,603 @@
+mod group_batches;
+mod group_max_len;
+mod group_windows;
+mod merge_adjacent_groups;
+mod then_group_by_key;
+
+pub use self::group_batches::{GroupBatches, TryGroupBatches, Oversized, OversizedGroup};
+pub use self::group_max_len::{GroupMaxLen, GroupPart};
+pub use self::group_windows::{GroupWindows, GroupPairs, GroupPairsMut};
+pub use self::merge_adjacent_groups::MergeAdjacentGroupsBy;
+pub use self::then_group_by_key::{SearchGroupByKey, ThenGroupByKey};
+pub(crate) use self::group_batches::UnitWeight;
+
+use std::slice::{from_raw_parts, from_raw_parts_mut};
+use std::str::{from_utf8_unchecked, from_utf8_unchecked_mut};
+
+mod private {
+    pub trait Sealed {}
+
+    impl<T> Sealed for &[T] {}
+    impl<T> Sealed for &mut [T] {}
+    impl Sealed for &str {}
+    impl Sealed for &mut str {}
+}
+
+/// A group returned by the group iterators, either a shared or a *mutable* slice or `str`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Group: Sized + private::Sealed {
+    /// Returns the number of elements in the group, the number of `char` for a `str`.
+    fn len(&self) -> usize;
+
+    /// Divides the group into two at an element index.
+    fn split_at(self, mid: usize) -> (Self, Self);
+
+    /// Divides the group into two, the second part containing the last `count` elements.
+    fn split_last_at(self, count: usize) -> (Self, Self);
+
+    /// Joins two groups, `other` must directly follow `self` in memory.
+    ///
+    /// # Safety
+    ///
+    /// Both groups must come from the same original slice, `other` starting
+    /// right where `self` ends.
+    unsafe fn join(self, other: Self) -> Self;
+
+    /// Returns `true` if the group has a length of 0.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A group returned by the slice group iterators, either a shared or a *mutable* slice.
+pub trait GroupSlice: Group {
+    /// The type of the elements of the group.
+    type Elem;
+
+    /// Returns the elements of the group as a shared slice.
+    fn as_slice(&self) -> &[Self::Elem];
+}
+
+impl<T> Group for &[T] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        <[T]>::split_at(self, mid)
+    }
+
+    #[inline]
+    fn split_last_at(self, count: usize) -> (Self, Self) {
+        let mid = <[T]>::len(self) - count;
+        <[T]>::split_at(self, mid)
+    }
+
+    #[inline]
+    unsafe fn join(self, other: Self) -> Self {
+        debug_assert!(self.as_ptr().add(self.len()) == other.as_ptr());
+        from_raw_parts(self.as_ptr(), self.len() + other.len())
+    }
+}
+
+impl<T> GroupSlice for &[T] {
+    type Elem = T;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> Group for &mut [T] {
+    #[inline]
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    #[inline]
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        <[T]>::split_at_mut(self, mid)
+    }
+
+    #[inline]
+    fn split_last_at(self, count: usize) -> (Self, Self) {
+        let mid = <[T]>::len(self) - count;
+        <[T]>::split_at_mut(self, mid)
+    }
+
+    #[inline]
+    unsafe fn join(self, other: Self) -> Self {
+        debug_assert!(self.as_ptr().add(self.len()) == other.as_ptr());
+        let len = self.len() + other.len();
+        from_raw_parts_mut(self.as_mut_ptr(), len)
+    }
+}
+
+impl<T> GroupSlice for &mut [T] {
+    type Elem = T;
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        self
+    }
+}
+
+fn char_index(string: &str, mid: usize) -> usize {
+    string.char_indices().nth(mid).map_or(string.len(), |(i, _)| i)
+}
+
+fn char_index_from_end(string: &str, count: usize) -> usize {
+    match count.checked_sub(1) {
+        Some(n) => string.char_indices().rev().nth(n).map_or(0, |(i, _)| i),
+        None => string.len(),
+    }
+}
+
+impl Group for &str {
+    #[inline]
+    fn len(&self) -> usize {
+        self.chars().count()
+    }
+
+    #[inline]
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        str::split_at(self, char_index(self, mid))
+    }
+
+    #[inline]
+    fn split_last_at(self, count: usize) -> (Self, Self) {
+        str::split_at(self, char_index_from_end(self, count))
+    }
+
+    #[inline]
+    unsafe fn join(self, other: Self) -> Self {
+        let (left, right) = (self.as_bytes(), other.as_bytes());
+        from_utf8_unchecked(Group::join(left, right))
+    }
+}
+
+impl Group for &mut str {
+    #[inline]
+    fn len(&self) -> usize {
+        self.chars().count()
+    }
+
+    #[inline]
+    fn split_at(self, mid: usize) -> (Self, Self) {
+        let index = char_index(self, mid);
+        str::split_at_mut(self, index)
+    }
+
+    #[inline]
+    fn split_last_at(self, count: usize) -> (Self, Self) {
+        let index = char_index_from_end(self, count);
+        str::split_at_mut(self, index)
+    }
+
+    #[inline]
+    unsafe fn join(self, other: Self) -> Self {
+        let (left, right) = (self.as_bytes_mut(), other.as_bytes_mut());
+        from_utf8_unchecked_mut(Group::join(left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GroupBy, GroupByMut, StrGroupBy, GroupIterator, GroupPart, SearchMode, Oversized, OversizedGroup};
+
+    #[test]
+    fn batches_whole_groups() {
+        let slice = &[1, 1, 2, 3, 3, 3, 4, 5, 5];
+
+        let mut iter = slice.linear_group().group_batches(4);
+
+        assert_eq!(iter.next(), Some(&[1, 1, 2][..]));
+        assert_eq!(iter.next(), Some(&[3, 3, 3, 4][..]));
+        assert_eq!(iter.next(), Some(&[5, 5][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn rev_batches_whole_groups() {
+        let slice = &[1, 1, 2, 3, 3, 3, 4, 5, 5];
+
+        let mut iter = slice.binary_group().group_batches(4).rev();
+
+        assert_eq!(iter.next(), Some(&[4, 5, 5][..]));
+        assert_eq!(iter.next(), Some(&[2, 3, 3, 3][..]));
+        assert_eq!(iter.next(), Some(&[1, 1][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn double_ended_batches_dont_cross() {
+        let slice = &[1, 1, 2, 3, 3, 3, 4, 5, 5];
+
+        let mut iter = slice.exponential_group().group_batches(3);
+
+        assert_eq!(iter.next(), Some(&[1, 1, 2][..]));
+        assert_eq!(iter.next_back(), Some(&[4, 5, 5][..]));
+        assert_eq!(iter.next(), Some(&[3, 3, 3][..]));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn oversized_group_alone() {
+        let slice = &[1, 2, 2, 2, 2, 2, 3];
+
+        let mut iter = slice.linear_group().group_batches(3);
+
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next(), Some(&[2, 2, 2, 2, 2][..]));
+        assert_eq!(iter.next(), Some(&[3][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn oversized_group_split() {
+        let slice = &[1, 2, 2, 2, 2, 2, 3];
+
+        let mut iter = slice.linear_group().group_batches(3).oversized(Oversized::Split);
+
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+        assert_eq!(iter.next(), Some(&[2, 2][..]));
+        assert_eq!(iter.next(), Some(&[3][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn rev_oversized_group_split() {
+        let slice = &[1, 2, 2, 2, 2, 2, 3];
+
+        let mut iter = slice.linear_group().group_batches(3).oversized(Oversized::Split).rev();
+
+        assert_eq!(iter.next(), Some(&[3][..]));
+        assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+        assert_eq!(iter.next(), Some(&[2, 2][..]));
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn oversized_group_error() {
+        let slice = &[1, 2, 2, 2, 2, 2, 3];
+
+        let mut iter = slice.linear_group().group_batches(3).try_oversized();
+
+        assert_eq!(iter.next(), Some(Ok(&[1][..])));
+        assert_eq!(iter.next(), Some(Err(OversizedGroup(&[2, 2, 2, 2, 2][..]))));
+        assert_eq!(iter.next(), Some(Ok(&[3][..])));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn batches_by_weight() {
+        let slice = &["a", "bb", "bb", "cccc", "d"];
+
+        let mut iter = slice.linear_group_by_key(|s| s.len()).group_batches_by_weight(5, |s| s.len());
+
+        assert_eq!(iter.next(), Some(&["a", "bb", "bb"][..]));
+        assert_eq!(iter.next(), Some(&["cccc", "d"][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn batches_by_weight_split() {
+        let slice = &[1, 4, 4, 4, 2];
+
+        let mut iter = slice.linear_group()
+            .group_batches_by_weight(9, |&x| x)
+            .oversized(Oversized::Split);
+
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next(), Some(&[4, 4][..]));
+        assert_eq!(iter.next(), Some(&[4][..]));
+        assert_eq!(iter.next(), Some(&[2][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn batches_weight_near_max() {
+        let slice = &[1, 2, 2, 3];
+        let weight = |&x: &i32| if x == 2 { usize::MAX - 2 } else { 1 };
+
+        let mut iter = slice.linear_group().group_batches_by_weight(usize::MAX - 1, weight);
+
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next(), Some(&[2, 2][..]));
+        assert_eq!(iter.next(), Some(&[3][..]));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = slice.linear_group()
+            .group_batches_by_weight(usize::MAX - 1, weight)
+            .oversized(Oversized::Split);
+
+        assert_eq!(iter.next(), Some(&[1][..]));
+        assert_eq!(iter.next(), Some(&[2][..]));
+        assert_eq!(iter.next(), Some(&[2][..]));
+        assert_eq!(iter.next(), Some(&[3][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn split_chunks_both_ends() {
+        let slice = &[2, 2, 2, 2, 2, 2, 2];
+
+        let mut iter = slice.linear_group().group_batches(3).oversized(Oversized::Split);
+
+        assert_eq!(iter.next(), Some(&[2, 2, 2][..]));
+        assert_eq!(iter.next_back(), Some(&[2, 2, 2][..]));
+        assert_eq!(iter.next(), Some(&[2][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    #[allow(clippy::double_ended_iterator_last)]
+    fn batches_last_is_forward_last() {
+        let slice = &[1, 2, 3];
+
+        // from the back the batches are [2, 3] and [1]
+        let iter = slice.linear_group().group_batches(2);
+        assert_eq!(iter.last(), Some(&[3][..]));
+    }
+
+    #[test]
+    fn batches_mut() {
+        let slice = &mut [1, 1, 2, 3, 3, 3, 4, 5, 5];
+
+        for batch in slice.linear_group_mut().group_batches(4) {
+            let first = batch[0];
+            batch.iter_mut().for_each(|x| *x = first);
+        }
+
+        assert_eq!(slice, &[1, 1, 1, 3, 3, 3, 3, 5, 5]);
+    }
+
+    #[test]
+    fn batches_empty_slice() {
+        let slice: &[i32] = &[];
+
+        let mut iter = slice.linear_group().group_batches(4);
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn max_len_chunks() {
+        let slice = &[1, 1, 1, 1, 1, 2, 3, 3];
+
+        let mut iter = slice.binary_group_by_max_len(|a, b| a == b, 2);
+
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[1, 1][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, &[1, 1][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, &[1][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[2][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[3, 3][..])));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn rev_max_len_chunks() {
+        let slice = &[1, 1, 1, 1, 1, 2, 3, 3];
+
+        let mut iter = slice.exponential_group_by_key_max_len(|&x| x, 2).rev();
+
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[3, 3][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[2][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, &[1][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, &[1, 1][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[1, 1][..])));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn double_ended_max_len_chunks_dont_cross() {
+        let slice = &[1, 1, 1, 1, 1, 1, 1];
+
+        let mut iter = slice.linear_group_by_max_len(|a, b| a == b, 3);
+
+        assert_eq!(iter.next_back(), Some((GroupPart::Continuation, &[1][..])));
+        assert_eq!(iter.next(), Some((GroupPart::Start, &[1, 1, 1][..])));
+        assert_eq!(iter.next_back(), Some((GroupPart::Continuation, &[1, 1, 1][..])));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn max_len_chunks_mut() {
+        let slice = &mut [1, 1, 1, 1, 1, 2];
+
+        for (part, chunk) in slice.linear_group_by_key_max_len_mut(|&x| x, 2) {
+            if part == GroupPart::Continuation {
+                chunk.iter_mut().for_each(|x| *x = 0);
+            }
+        }
+
+        assert_eq!(slice, &[1, 1, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn str_max_len_chunks() {
+        let string = "aaaaa饰饰饰b";
+
+        let mut iter = string.linear_group_by_max_len(|a, b| a == b, 2);
+
+        assert_eq!(iter.next(), Some((GroupPart::Start, "aa")));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, "aa")));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, "a")));
+        assert_eq!(iter.next(), Some((GroupPart::Start, "饰饰")));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, "饰")));
+        assert_eq!(iter.next(), Some((GroupPart::Start, "b")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn double_ended_str_max_len_chunks() {
+        let string = "饰饰饰饰饰饰饰";
+
+        let mut iter = string.linear_group_by_max_len(|a, b| a == b, 3);
+
+        assert_eq!(iter.next_back(), Some((GroupPart::Continuation, "饰")));
+        assert_eq!(iter.next(), Some((GroupPart::Start, "饰饰饰")));
+        assert_eq!(iter.next_back(), Some((GroupPart::Continuation, "饰饰饰")));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn rev_str_max_len_chunks() {
+        let string = "aaaaa饰饰饰b";
+
+        let mut iter = string.linear_group_by_key_max_len(|c| c, 2).rev();
+
+        assert_eq!(iter.next(), Some((GroupPart::Start, "b")));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, "饰")));
+        assert_eq!(iter.next(), Some((GroupPart::Start, "饰饰")));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, "a")));
+        assert_eq!(iter.next(), Some((GroupPart::Continuation, "aa")));
+        assert_eq!(iter.next(), Some((GroupPart::Start, "aa")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn then_group_by_key_rev() {
+        let slice = &[(1, 'a'), (1, 'b'), (2, 'b'), (3, 'c'), (3, 'c')];
+
+        let mut iter = slice.exponential_group_by_key(|x| x.0)
+                            .then_group_by_key(SearchMode::Binary, |x: &(i32, char)| x.1)
+                            .rev();
+
+        let (group, inner) = iter.next().unwrap();
+        assert_eq!(group, &slice[3..]);
+        assert_eq!(inner.collect::<Vec<_>>(), vec![&slice[3..]]);
+
+        let (group, inner) = iter.next().unwrap();
+        assert_eq!(group, &slice[2..3]);
+        assert_eq!(inner.count(), 1);
+
+        let (group, inner) = iter.next().unwrap();
+        assert_eq!(group, &slice[..2]);
+        assert_eq!(inner.rev().collect::<Vec<_>>(), vec![&slice[1..2], &slice[..1]]);
+
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn merge_tiny_groups() {
+        let slice = &[1, 2, 2, 3, 4, 4, 4, 5];
+
+        let iter = slice.linear_group().merge_adjacent_groups_by(|a, b| a.len() == 1 && b.len() == 1);
+        let groups: Vec<_> = iter.collect();
+        assert_eq!(groups, vec![&[1][..], &[2, 2], &[3], &[4, 4, 4], &[5]]);
+
+        let iter = slice.linear_group().merge_adjacent_groups_by(|a, b| a.len() <= 2 && b.len() <= 2);
+        let groups: Vec<_> = iter.collect();
+        assert_eq!(groups, vec![&[1, 2, 2][..], &[3], &[4, 4, 4], &[5]]);
+    }
+
+    #[test]
+    fn merge_close_groups_rev() {
+        let slice = &[1, 1, 2, 5, 6, 6, 10];
+
+        let close = |a: &[i32], b: &[i32]| b[0] - a[a.len() - 1] <= 1;
+
+        let iter = slice.binary_group().merge_adjacent_groups_by(close);
+        let groups: Vec<_> = iter.collect();
+        assert_eq!(groups, vec![&[1, 1, 2][..], &[5, 6, 6], &[10]]);
+
+        let iter = slice.binary_group().merge_adjacent_groups_by(close).rev();
+        let groups: Vec<_> = iter.collect();
+        assert_eq!(groups, vec![&[10][..], &[5, 6, 6], &[1, 1, 2]]);
+    }
+
+    #[test]
+    #[allow(clippy::double_ended_iterator_last)]
+    fn merge_last_is_forward_last() {
+        let slice = &[1, 2, 3];
+
+        // from the back the first group is [2, 3]
+        let iter = slice.linear_group().merge_adjacent_groups_by(|a, b| a.len() + b.len() <= 2);
+        assert_eq!(iter.last(), Some(&[3][..]));
+    }
+
+    #[test]
+    fn merge_double_ended() {
+        let slice = &[1, 2, 3, 5, 7, 8];
+
+        let mut iter = slice.linear_group().merge_adjacent_groups_by(|a, b| a[a.len() - 1] + 1 == b[0]);
+
+        assert_eq!(iter.next(), Some(&[1, 2, 3][..]));
+        assert_eq!(iter.next_back(), Some(&[7, 8][..]));
+        assert_eq!(iter.next(), Some(&[5][..]));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn merge_mut_groups() {
+        let slice = &mut [1, 1, 2, 4, 4, 5];
+
+        let iter = slice.linear_group_mut().merge_adjacent_groups_by(|a, b| b[0] - a[a.len() - 1] == 1);
+        for group in iter {
+            let sum: i32 = group.iter().sum();
+            group.iter_mut().for_each(|x| *x = sum);
+        }
+
+        assert_eq!(slice, &[4, 4, 4, 13, 13, 13]);
+    }
+
+    #[test]
+    fn group_windows_of_three() {
+        let slice = &[1, 1, 2, 3, 3, 4];
+
+        let windows: Vec<_> = slice.linear_group().group_windows::<3>().collect();
+        assert_eq!(windows, vec![
+            [&[1, 1][..], &[2], &[3, 3]],
+            [&[2][..], &[3, 3], &[4]],
+        ]);
+
+        assert_eq!(slice.linear_group().group_windows::<5>().next(), None);
+    }
+
+    #[test]
+    fn group_pairs_str() {
+        let string = "aabccc";
+
+        let pairs: Vec<_> = string.linear_group().group_pairs().collect();
+        assert_eq!(pairs, vec![("aa", "b"), ("b", "ccc")]);
+
+        assert_eq!("aaa".linear_group().group_pairs().next(), None);
+    }
+
+    #[test]
+    fn group_pairs_mut_deltas() {
+        let slice = &mut [1, 1, 3, 3, 3, 8];
+
+        let mut pairs = slice.linear_group_mut().group_pairs_mut();
+        while let Some((prev, current)) = pairs.next() {
+            let delta = current[0] - prev[0];
+            current.iter_mut().for_each(|x| *x = delta);
+        }
+        assert_eq!(pairs.next(), None);
+
+        assert_eq!(slice, &[1, 1, 2, 2, 2, 6]);
+    }
+
+    #[test]
+    fn group_pairs_mut_single_group() {
+        let slice = &mut [1, 1];
+
+        let mut pairs = slice.linear_group_mut().group_pairs_mut();
+        assert_eq!(pairs.next(), None);
+        assert_eq!(pairs.next(), None);
+    }
+}
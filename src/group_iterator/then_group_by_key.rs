@@ -0,0 +1,163 @@
+use std::iter::FusedIterator;
+use std::fmt;
+use crate::{
+    SearchMode,
+    LinearGroupByKey,
+    BinaryGroupByKey,
+    ExponentialGroupByKey,
+};
+
+/// An iterator that will return non-overlapping groups in the slice using
+/// the search method chosen at runtime.
+///
+/// It will give an element to the given function, producing a key and comparing
+/// the keys to determine groups.
+pub enum SearchGroupByKey<'a, T: 'a, F> {
+    Linear(LinearGroupByKey<'a, T, F>),
+    Binary(BinaryGroupByKey<'a, T, F>),
+    Exponential(ExponentialGroupByKey<'a, T, F>),
+}
+
+impl<'a, T: 'a, F, K> SearchGroupByKey<'a, T, F>
+where F: Fn(&T) -> K,
+      K: PartialEq,
+{
+    pub fn new(slice: &'a [T], mode: SearchMode, func: F) -> Self {
+        match mode {
+            SearchMode::Linear => SearchGroupByKey::Linear(LinearGroupByKey::new(slice, func)),
+            SearchMode::Binary => SearchGroupByKey::Binary(BinaryGroupByKey::new(slice, func)),
+            SearchMode::Exponential => SearchGroupByKey::Exponential(ExponentialGroupByKey::new(slice, func)),
+        }
+    }
+}
+
+impl<'a, T: 'a, F> SearchGroupByKey<'a, T, F> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        match self {
+            SearchGroupByKey::Linear(iter) => iter.remainder(),
+            SearchGroupByKey::Binary(iter) => iter.remainder(),
+            SearchGroupByKey::Exponential(iter) => iter.remainder(),
+        }
+    }
+}
+
+impl<'a, T: 'a, F, K> Iterator for SearchGroupByKey<'a, T, F>
+where F: Fn(&T) -> K,
+      K: PartialEq,
+{
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            SearchGroupByKey::Linear(iter) => iter.next(),
+            SearchGroupByKey::Binary(iter) => iter.next(),
+            SearchGroupByKey::Exponential(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            SearchGroupByKey::Linear(iter) => iter.size_hint(),
+            SearchGroupByKey::Binary(iter) => iter.size_hint(),
+            SearchGroupByKey::Exponential(iter) => iter.size_hint(),
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, T: 'a, F, K> DoubleEndedIterator for SearchGroupByKey<'a, T, F>
+where F: Fn(&T) -> K,
+      K: PartialEq,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            SearchGroupByKey::Linear(iter) => iter.next_back(),
+            SearchGroupByKey::Binary(iter) => iter.next_back(),
+            SearchGroupByKey::Exponential(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<'a, T: 'a, F, K> FusedIterator for SearchGroupByKey<'a, T, F>
+where F: Fn(&T) -> K,
+      K: PartialEq,
+{ }
+
+unsafe impl<'a, T: 'a, F, K> crate::GroupIterator for SearchGroupByKey<'a, T, F>
+where F: Fn(&T) -> K,
+      K: PartialEq,
+{ }
+
+impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for SearchGroupByKey<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SearchGroupByKey")
+            .field("remainder", &self.remainder())
+            .finish()
+    }
+}
+
+/// An iterator that returns every outer group along with an iterator
+/// on the inner groups of it, determined by the given key function.
+pub struct ThenGroupByKey<I, F> {
+    iter: I,
+    mode: SearchMode,
+    func: F,
+}
+
+impl<I, F> ThenGroupByKey<I, F> {
+    pub fn new(iter: I, mode: SearchMode, func: F) -> Self {
+        ThenGroupByKey { iter, mode, func }
+    }
+}
+
+impl<'a, T: 'a, I, F, K> Iterator for ThenGroupByKey<I, F>
+where I: Iterator<Item = &'a [T]>,
+      F: Fn(&T) -> K + Clone,
+      K: PartialEq,
+{
+    type Item = (&'a [T], SearchGroupByKey<'a, T, F>);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let group = self.iter.next()?;
+        Some((group, SearchGroupByKey::new(group, self.mode, self.func.clone())))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I, F, K> DoubleEndedIterator for ThenGroupByKey<I, F>
+where I: DoubleEndedIterator<Item = &'a [T]>,
+      F: Fn(&T) -> K + Clone,
+      K: PartialEq,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let group = self.iter.next_back()?;
+        Some((group, SearchGroupByKey::new(group, self.mode, self.func.clone())))
+    }
+}
+
+impl<'a, T: 'a, I, F, K> FusedIterator for ThenGroupByKey<I, F>
+where I: FusedIterator<Item = &'a [T]>,
+      F: Fn(&T) -> K + Clone,
+      K: PartialEq,
+{ }
+
+impl<I: fmt::Debug, F> fmt::Debug for ThenGroupByKey<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ThenGroupByKey")
+            .field("iter", &self.iter)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
@@ -0,0 +1,167 @@
+use std::ops::Range;
+use std::fmt;
+use crate::{SearchMode, SearchGroupByKey};
+
+/// A tuple of `(SearchMode, key function)` pairs, one for each level of a [`GroupTree`].
+///
+/// It is implemented for the tuples of up to six pairs.
+///
+/// [`GroupTree`]: struct.GroupTree.html
+pub trait GroupKeys<T> {
+    /// Returns the number of levels.
+    fn depth(&self) -> usize;
+
+    /// Gives the length of every group of the slice at the given level to the function.
+    fn for_each_group_len(&self, level: usize, slice: &[T], f: &mut dyn FnMut(usize));
+}
+
+fn for_each_group_len<T, F, K>(mode: SearchMode, func: &F, slice: &[T], f: &mut dyn FnMut(usize))
+where F: Fn(&T) -> K,
+      K: PartialEq,
+{
+    SearchGroupByKey::new(slice, mode, func).for_each(|group| f(group.len()))
+}
+
+macro_rules! group_keys {
+    ($depth:expr; $($idx:tt: $func:ident, $key:ident),+) => {
+        impl<T, $($func, $key),+> GroupKeys<T> for ($((SearchMode, $func),)+)
+        where $($func: Fn(&T) -> $key, $key: PartialEq),+
+        {
+            fn depth(&self) -> usize {
+                $depth
+            }
+
+            fn for_each_group_len(&self, level: usize, slice: &[T], f: &mut dyn FnMut(usize)) {
+                match level {
+                    $($idx => for_each_group_len((self.$idx).0, &(self.$idx).1, slice, f),)+
+                    _ => panic!("level {} is out of the depth {}", level, $depth),
+                }
+            }
+        }
+    }
+}
+
+group_keys!{ 1; 0: F0, K0 }
+group_keys!{ 2; 0: F0, K0, 1: F1, K1 }
+group_keys!{ 3; 0: F0, K0, 1: F1, K1, 2: F2, K2 }
+group_keys!{ 4; 0: F0, K0, 1: F1, K1, 2: F2, K2, 3: F3, K3 }
+group_keys!{ 5; 0: F0, K0, 1: F1, K1, 2: F2, K2, 3: F3, K3, 4: F4, K4 }
+group_keys!{ 6; 0: F0, K0, 1: F1, K1, 2: F2, K2, 3: F3, K3, 4: F4, K4, 5: F5, K5 }
+
+/// A group of a [`GroupTree`].
+///
+/// [`GroupTree`]: struct.GroupTree.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupNode {
+    /// The level of the group, `0` for the outermost groups.
+    pub depth: usize,
+    /// The range of the elements of the group in the slice.
+    pub range: Range<usize>,
+    /// The index of the parent group in the nodes of the tree.
+    pub parent: Option<usize>,
+    /// The range of the child groups in the nodes of the tree.
+    pub children: Range<usize>,
+}
+
+/// A tree of nested groups, every level of groups being determined
+/// by a key function inside the groups of the previous level.
+///
+/// The nodes are stored level by level, the children of a node being contiguous.
+pub struct GroupTree<'a, T: 'a> {
+    slice: &'a [T],
+    nodes: Vec<GroupNode>,
+    roots: usize,
+}
+
+impl<'a, T: 'a> GroupTree<'a, T> {
+    /// Returns all the nodes of the tree, level by level.
+    pub fn nodes(&self) -> &[GroupNode] {
+        &self.nodes
+    }
+
+    /// Returns the outermost groups of the tree.
+    pub fn roots(&self) -> &[GroupNode] {
+        &self.nodes[..self.roots]
+    }
+
+    /// Returns the child groups of the given node.
+    pub fn children(&self, node: &GroupNode) -> &[GroupNode] {
+        &self.nodes[node.children.clone()]
+    }
+
+    /// Returns the parent group of the given node.
+    pub fn parent(&self, node: &GroupNode) -> Option<&GroupNode> {
+        node.parent.map(|i| &self.nodes[i])
+    }
+
+    /// Returns the elements of the given group.
+    pub fn group(&self, node: &GroupNode) -> &'a [T] {
+        &self.slice[node.range.clone()]
+    }
+}
+
+impl<'a, T: 'a + fmt::Debug> fmt::Debug for GroupTree<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GroupTree")
+            .field("slice", &self.slice)
+            .field("nodes", &self.nodes)
+            .finish()
+    }
+}
+
+/// Builds a tree of nested groups of the slice, the groups of every level being
+/// determined by the key function and the search method of the corresponding pair.
+///
+/// ```rust
+/// use slice_group_by::{group_tree_by_keys, SearchMode};
+///
+/// // (region, store, amount)
+/// let rows = &[('e', 1, 10), ('e', 1, 25), ('e', 2, 5), ('u', 3, 40)];
+///
+/// let tree = group_tree_by_keys(rows, (
+///     (SearchMode::Binary, |r: &(char, u32, u32)| r.0),
+///     (SearchMode::Linear, |r: &(char, u32, u32)| r.1),
+/// ));
+///
+/// let roots = tree.roots();
+/// assert_eq!(roots.len(), 2);
+/// assert_eq!(tree.group(&roots[1]), &[('u', 3, 40)][..]);
+///
+/// let stores = tree.children(&roots[0]);
+/// assert_eq!(tree.group(&stores[0]), &rows[..2]);
+/// assert_eq!(tree.group(&stores[1]), &rows[2..3]);
+/// assert_eq!(tree.parent(&stores[1]), Some(&roots[0]));
+/// ```
+pub fn group_tree_by_keys<'a, T, G>(slice: &'a [T], keys: G) -> GroupTree<'a, T>
+where G: GroupKeys<T>,
+{
+    let mut nodes = Vec::new();
+
+    let mut start = 0;
+    keys.for_each_group_len(0, slice, &mut |len| {
+        nodes.push(GroupNode { depth: 0, range: start..start + len, parent: None, children: 0..0 });
+        start += len;
+    });
+    let roots = nodes.len();
+
+    let mut level = 0..roots;
+    for depth in 1..keys.depth() {
+        for parent in level.clone() {
+            let range = nodes[parent].range.clone();
+            let first_child = nodes.len();
+
+            let mut start = range.start;
+            keys.for_each_group_len(depth, &slice[range], &mut |len| {
+                let range = start..start + len;
+                nodes.push(GroupNode { depth, range, parent: Some(parent), children: 0..0 });
+                start += len;
+            });
+
+            let last_child = nodes.len();
+            nodes[parent].children = first_child..last_child;
+        }
+        level = level.end..nodes.len();
+    }
+
+    GroupTree { slice, nodes, roots }
+}
@@ -0,0 +1,54 @@
+mod group_tree;
+
+pub use self::group_tree::{group_tree_by_keys, GroupTree, GroupNode, GroupKeys};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SearchMode;
+
+    #[test]
+    fn three_levels() {
+        let slice = &[(1, 'a', 0), (1, 'a', 0), (1, 'a', 1), (1, 'b', 1), (2, 'b', 1), (2, 'b', 1)];
+
+        let tree = group_tree_by_keys(slice, (
+            (SearchMode::Exponential, |x: &(i32, char, i32)| x.0),
+            (SearchMode::Binary, |x: &(i32, char, i32)| x.1),
+            (SearchMode::Linear, |x: &(i32, char, i32)| x.2),
+        ));
+
+        let roots = tree.roots();
+        assert_eq!(roots.len(), 2);
+        assert_eq!(roots[0].range, 0..4);
+        assert_eq!(roots[1].range, 4..6);
+
+        let inner = tree.children(&roots[0]);
+        assert_eq!(inner.len(), 2);
+        assert_eq!(tree.group(&inner[0]), &slice[0..3]);
+        assert_eq!(tree.group(&inner[1]), &slice[3..4]);
+
+        let leaves = tree.children(&inner[0]);
+        assert_eq!(leaves.len(), 2);
+        assert_eq!(leaves[0].depth, 2);
+        assert_eq!(leaves[0].range, 0..2);
+        assert_eq!(leaves[1].range, 2..3);
+        assert_eq!(tree.parent(&leaves[1]), Some(&inner[0]));
+        assert!(tree.children(&leaves[1]).is_empty());
+
+        let inner = tree.children(&roots[1]);
+        assert_eq!(inner.len(), 1);
+        assert_eq!(tree.children(&inner[0]).len(), 1);
+
+        assert_eq!(tree.nodes().len(), 2 + 3 + 4);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let slice: &[i32] = &[];
+
+        let tree = group_tree_by_keys(slice, ((SearchMode::Linear, |x: &i32| *x),));
+
+        assert!(tree.roots().is_empty());
+        assert!(tree.nodes().is_empty());
+    }
+}
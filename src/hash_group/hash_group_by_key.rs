@@ -0,0 +1,140 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Range;
+use std::fmt;
+use crate::LinearGroupByKey;
+
+/// The elements of a slice grouped by key even when they are not contiguous,
+/// the keys being ordered by their first appearance in the slice.
+///
+/// Every key is associated with the contiguous runs of its elements in the slice.
+pub struct HashGroups<'a, T: 'a, K> {
+    slice: &'a [T],
+    positions: HashMap<K, usize>,
+    groups: Vec<(K, Vec<Range<usize>>)>,
+}
+
+impl<'a, T: 'a, K> HashGroups<'a, T, K>
+where K: Hash + Eq,
+{
+    /// Returns the number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if there is no key.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns the keys in the order of their first appearance.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.groups.iter().map(|(key, _)| key)
+    }
+
+    /// Returns the keys along with the contiguous runs of their elements,
+    /// in the order of their first appearance.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &[Range<usize>])> + ExactSizeIterator {
+        self.groups.iter().map(|(key, runs)| (key, runs.as_slice()))
+    }
+
+    /// Returns the contiguous runs of the elements of the given key.
+    pub fn runs<Q>(&self, key: &Q) -> Option<&[Range<usize>]>
+    where K: Borrow<Q>,
+          Q: Hash + Eq + ?Sized,
+    {
+        self.positions.get(key).map(|&i| self.groups[i].1.as_slice())
+    }
+
+    /// Returns the indices of the elements of the given key.
+    pub fn indices<Q>(&self, key: &Q) -> Option<impl Iterator<Item = usize> + '_>
+    where K: Borrow<Q>,
+          Q: Hash + Eq + ?Sized,
+    {
+        self.runs(key).map(|runs| runs.iter().flat_map(Range::clone))
+    }
+
+    /// Returns the elements of the given key.
+    pub fn elements<Q>(&self, key: &Q) -> Option<impl Iterator<Item = &'a T> + '_>
+    where K: Borrow<Q>,
+          Q: Hash + Eq + ?Sized,
+    {
+        let slice = self.slice;
+        self.runs(key).map(move |runs| runs.iter().flat_map(move |run| &slice[run.clone()]))
+    }
+
+    /// Returns the keys along with the indices of their elements,
+    /// in the order of their first appearance.
+    pub fn into_indices(self) -> Vec<(K, Vec<usize>)> {
+        self.groups.into_iter().map(|(key, runs)| {
+            (key, runs.into_iter().flatten().collect())
+        })
+        .collect()
+    }
+
+    /// Returns the keys along with their elements,
+    /// in the order of their first appearance.
+    pub fn into_elements(self) -> Vec<(K, Vec<&'a T>)> {
+        let slice = self.slice;
+        self.groups.into_iter().map(|(key, runs)| {
+            (key, runs.into_iter().flat_map(|run| &slice[run]).collect())
+        })
+        .collect()
+    }
+}
+
+impl<'a, T: 'a + fmt::Debug, K: fmt::Debug> fmt::Debug for HashGroups<'a, T, K> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HashGroups")
+            .field("slice", &self.slice)
+            .field("groups", &self.groups)
+            .finish()
+    }
+}
+
+/// Groups the elements of the slice by key, the elements of a group
+/// not being necessarily contiguous.
+///
+/// The contiguous runs of elements with the same key are found using a [`LinearGroupByKey`].
+///
+/// [`LinearGroupByKey`]: struct.LinearGroupByKey.html
+///
+/// ```rust
+/// use slice_group_by::hash_group_by_key;
+///
+/// let slice = &["b1", "b2", "a1", "b3", "a2"];
+///
+/// let groups = hash_group_by_key(slice, |s| s.as_bytes()[0]);
+///
+/// assert_eq!(groups.keys().collect::<Vec<_>>(), vec![&b'b', &b'a']);
+/// assert_eq!(groups.runs(&b'b'), Some(&[0..2, 3..4][..]));
+/// assert_eq!(groups.indices(&b'a').unwrap().collect::<Vec<_>>(), vec![2, 4]);
+/// assert_eq!(groups.elements(&b'b').unwrap().collect::<Vec<_>>(), vec![&"b1", &"b2", &"b3"]);
+/// ```
+pub fn hash_group_by_key<T, F, K>(slice: &[T], mut func: F) -> HashGroups<'_, T, K>
+where F: FnMut(&T) -> K,
+      K: Hash + Eq + Clone,
+{
+    let mut runs = Vec::new();
+    let mut start = 0;
+    for group in LinearGroupByKey::new(slice, &mut func) {
+        runs.push(start..start + group.len());
+        start += group.len();
+    }
+
+    let mut positions: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<(K, Vec<Range<usize>>)> = Vec::new();
+    for run in runs {
+        let key = func(&slice[run.start]);
+        match positions.get(&key) {
+            Some(&i) => groups[i].1.push(run),
+            None => {
+                positions.insert(key.clone(), groups.len());
+                groups.push((key, vec![run]));
+            },
+        }
+    }
+
+    HashGroups { slice, positions, groups }
+}
@@ -0,0 +1,67 @@
+mod hash_group_by_key;
+
+pub use self::hash_group_by_key::{hash_group_by_key, HashGroups};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insertion_ordered() {
+        let slice = &[3, 3, 1, 2, 2, 3, 1, 1, 3];
+
+        let groups = hash_group_by_key(slice, |x| *x);
+
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups.keys().copied().collect::<Vec<_>>(), vec![3, 1, 2]);
+        assert_eq!(groups.runs(&3), Some(&[0..2, 5..6, 8..9][..]));
+        assert_eq!(groups.runs(&1), Some(&[2..3, 6..8][..]));
+        assert_eq!(groups.runs(&4), None);
+
+        let runs: Vec<_> = groups.iter().rev().map(|(k, runs)| (*k, runs.len())).collect();
+        assert_eq!(runs, vec![(2, 1), (1, 2), (3, 3)]);
+
+        assert_eq!(groups.into_indices(), vec![
+            (3, vec![0, 1, 5, 8]),
+            (1, vec![2, 6, 7]),
+            (2, vec![3, 4]),
+        ]);
+    }
+
+    #[test]
+    fn borrowed_keys() {
+        let slice = &[("b", 1), ("a", 2), ("b", 3)];
+
+        let groups = hash_group_by_key(slice, |x| x.0.to_string());
+
+        let elements: Vec<_> = groups.elements("b").unwrap().map(|x| x.1).collect();
+        assert_eq!(elements, vec![1, 3]);
+
+        assert_eq!(groups.into_elements(), vec![
+            ("b".to_string(), vec![&("b", 1), &("b", 3)]),
+            ("a".to_string(), vec![&("a", 2)]),
+        ]);
+    }
+
+    #[test]
+    fn alternating_runs() {
+        let slice = &[1, 1, 2, 1, 3, 3, 2];
+
+        let groups = hash_group_by_key(slice, |&x| vec![x]);
+
+        assert_eq!(groups.runs(&[1][..]), Some(&[0..2, 3..4][..]));
+        assert_eq!(groups.runs(&[2][..]), Some(&[2..3, 6..7][..]));
+        assert_eq!(groups.runs(&[4][..]), None);
+        assert_eq!(groups.keys().collect::<Vec<_>>(), vec![&vec![1], &vec![2], &vec![3]]);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let slice: &[i32] = &[];
+
+        let groups = hash_group_by_key(slice, |x| *x);
+
+        assert!(groups.is_empty());
+        assert!(groups.into_indices().is_empty());
+    }
+}
@@ -83,6 +83,8 @@
 #![cfg_attr(feature = "nightly", feature(test))]
 
 #![cfg_attr(all(not(test), not(feature = "std")), no_std)]
+// every module is a directory holding its tests and a file of the same name
+#![allow(clippy::module_inception)]
 #[cfg(all(not(test), not(feature = "std")))]
 extern crate core as std;
 
@@ -117,6 +119,10 @@ macro_rules! group_by_wrapped {
         impl<'a, T: 'a> std::iter::FusedIterator for $name<'a, T>
         where T: PartialEq,
         { }
+
+        unsafe impl<'a, T: 'a> crate::GroupIterator for $name<'a, T>
+        where T: PartialEq,
+        { }
     }
 }
 
@@ -124,8 +130,30 @@ mod linear_group;
 mod binary_group;
 mod exponential_group;
 mod linear_str_group;
+mod group_iterator;
+mod group_boundaries;
+mod consecutive_ranges;
+mod coalesce_intervals;
+mod bucket_group;
+mod script_runs;
+mod delimited_group;
+mod record_slice;
+mod rows_group;
+mod column_group;
+#[cfg(feature = "std")]
+mod group_tree;
+#[cfg(feature = "std")]
+mod group_indices;
+#[cfg(feature = "std")]
+mod hash_group;
+#[cfg(feature = "std")]
+mod csr;
+#[cfg(feature = "std")]
+mod buf_read_group;
 
 use std::cmp::{self, Ordering};
+use std::ops::{Range, Sub};
+use self::group_iterator::UnitWeight;
 
 pub use self::linear_group::{
     LinearGroupByKey,
@@ -134,6 +162,21 @@ pub use self::linear_group::{
     LinearGroupByKeyMut,
     LinearGroupByMut,
     LinearGroupMut,
+    LinearGroupByAnchor,
+    LinearGroupByAnchorMut,
+    LinearGroupByKeyAnchor,
+    LinearGroupByKeyAnchorMut,
+    LinearGroupByFold,
+    LinearGroupByFoldMut,
+    LinearGroupStartingWith,
+    LinearGroupStartingWithMut,
+    LinearGroupEndingWith,
+    LinearGroupEndingWithMut,
+    GapGroupByKey,
+    GapGroupByKeyMut,
+    GapGroupByKeyStats,
+    GapGroupByKeyStatsMut,
+    GapStats,
 };
 
 pub use self::binary_group::{
@@ -161,6 +204,143 @@ pub use self::linear_str_group::{
     LinearStrGroupByKeyMut,
     LinearStrGroupByMut,
     LinearStrGroupMut,
+    LinearStrGroupByAnchor,
+    LinearStrGroupByAnchorMut,
+    LinearStrGroupByKeyAnchor,
+    LinearStrGroupByKeyAnchorMut,
+    LinearStrGroupByFold,
+    LinearStrGroupByFoldMut,
+    LinearStrGroupStartingWith,
+    LinearStrGroupStartingWithMut,
+    LinearStrGroupEndingWith,
+    LinearStrGroupEndingWithMut,
+    StrTokens,
+    CharClass,
+    StrGroupSpans,
+    StrGroupSpan,
+    LinesGroupByKey,
+};
+
+#[cfg(feature = "unicode-segmentation")]
+pub use self::linear_str_group::{
+    LinearStrGroupByGrapheme,
+    LinearStrGroupByGraphemeMut,
+};
+
+pub use self::group_iterator::{
+    Group,
+    GroupSlice,
+    GroupBatches,
+    TryGroupBatches,
+    Oversized,
+    OversizedGroup,
+    GroupMaxLen,
+    GroupPart,
+    GroupWindows,
+    GroupPairs,
+    GroupPairsMut,
+    MergeAdjacentGroupsBy,
+    SearchGroupByKey,
+    ThenGroupByKey,
+};
+
+pub use self::group_boundaries::{
+    group_boundaries_into,
+    group_boundaries_bitmap,
+};
+
+pub use self::consecutive_ranges::{
+    consecutive_ranges,
+    consecutive_ranges_by_step,
+    ConsecutiveRanges,
+    ConsecutiveInt,
+};
+
+pub use self::coalesce_intervals::{
+    coalesce_intervals,
+    coalesce_intervals_by_key,
+    coalesce_intervals_mut,
+    coalesce_intervals_by_key_mut,
+    CoalesceIntervals,
+    CoalesceIntervalsMut,
+    IntervalKey,
+    Tolerance,
+    NoGap,
+    MaxGap,
+};
+
+pub use self::bucket_group::{
+    BucketGroupByKey,
+    BucketKey,
+};
+
+pub use self::script_runs::{
+    ScriptRuns,
+    Script,
+};
+
+pub use self::delimited_group::{
+    delimited_group_by_field,
+    delimited_group_by_fields,
+    DelimitedGroupBy,
+};
+
+pub use self::record_slice::{
+    RecordSlice,
+    RecordSliceError,
+    RecordGroupBy,
+};
+
+pub use self::rows_group::{
+    rows_group_by_key,
+    rows_group_by_key_mut,
+    binary_rows_group_by_key,
+    binary_rows_group_by_key_mut,
+    exponential_rows_group_by_key,
+    exponential_rows_group_by_key_mut,
+    RowsGroupByKey,
+    RowsGroupByKeyMut,
+};
+
+pub use self::column_group::{
+    ColumnGroupBy,
+    Columns,
+};
+
+#[cfg(feature = "std")]
+pub use self::group_tree::{
+    group_tree_by_keys,
+    GroupTree,
+    GroupNode,
+    GroupKeys,
+};
+
+#[cfg(feature = "std")]
+pub use self::group_indices::{
+    group_indices_by_key,
+    GroupedIndices,
+    GroupedIndicesIter,
+    GroupElements,
+};
+
+#[cfg(feature = "std")]
+pub use self::hash_group::{
+    hash_group_by_key,
+    HashGroups,
+};
+
+#[cfg(feature = "std")]
+pub use self::csr::{
+    Csr,
+    CsrIter,
+    CsrRef,
+    CsrRefIter,
+};
+
+#[cfg(feature = "std")]
+pub use self::buf_read_group::{
+    BufReadGroupBy,
+    BufReadLinesGroupByKey,
 };
 
 #[cfg(feature = "nightly")]
@@ -176,6 +356,35 @@ unsafe fn offset_from<T>(to: *const T, from: *const T) -> usize {
     (to as usize - from as usize) / mem::size_of::<T>()
 }
 
+/// Returns the number of elements of the run starting at the first of `len` elements,
+/// `same(i)` returning `true` if the `i`th element is in the run.
+///
+/// The *binary* and *exponential* searches require the elements of the run to be contiguous.
+fn run_len<F>(len: usize, mode: SearchMode, same: F) -> usize
+where F: Fn(usize) -> bool,
+{
+    // the first index in `lo..hi` that is not in the run
+    let partition_point = |mut lo: usize, mut hi: usize| {
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if same(mid) { lo = mid + 1 } else { hi = mid }
+        }
+        lo
+    };
+
+    match mode {
+        SearchMode::Linear => (1..len).find(|&i| !same(i)).unwrap_or(len),
+        SearchMode::Binary => partition_point(1, len),
+        SearchMode::Exponential => {
+            let mut index = 1;
+            while index < len && same(index) {
+                index *= 2;
+            }
+            partition_point(index / 2 + 1, cmp::min(index, len))
+        },
+    }
+}
+
 /// Exponential searches this sorted slice for a given element.
 ///
 /// If the value is found then `Ok` is returned, containing the index of the matching element;
@@ -311,6 +520,96 @@ pub trait GroupBy<T>
     fn linear_group(&self) -> LinearGroup<T>
     where T: PartialEq;
 
+    /// Returns an iterator on slice groups using the *linear search* method, the predicate
+    /// is given the first element of the current group instead of the previous element.
+    fn linear_group_by_anchor<P>(&self, predicate: P) -> LinearGroupByAnchor<'_, T, P>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on slice groups based that will use the given function to generate keys
+    /// and determine groups based on them. It uses *linear search* to iterate over groups, the
+    /// predicate is given the key of the first element of the current group instead
+    /// of the key of the previous element.
+    fn linear_group_by_key_anchor<F, K, P>(&self, func: F, predicate: P) -> LinearGroupByKeyAnchor<'_, T, F, P>
+    where F: FnMut(&T) -> K,
+          P: FnMut(&K, &K) -> bool;
+
+    /// Returns an iterator on slice groups using the *linear search* method, the given
+    /// step function decides whether an element joins the current group by updating
+    /// a state that is reset to `init` at the start of every group.
+    ///
+    /// ```rust
+    /// use slice_group_by::GroupBy;
+    ///
+    /// let invoices = &[40, 30, 20, 50, 10, 90, 30];
+    ///
+    /// let mut iter = invoices.linear_group_by_fold(0, |total, &x| { *total += x; *total <= 100 });
+    ///
+    /// assert_eq!(iter.next(), Some(&[40, 30, 20][..]));
+    /// assert_eq!(iter.next(), Some(&[50, 10][..]));
+    /// assert_eq!(iter.next(), Some(&[90][..]));
+    /// assert_eq!(iter.next(), Some(&[30][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn linear_group_by_fold<S, F>(&self, init: S, step: F) -> LinearGroupByFold<'_, T, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, &T) -> bool;
+
+    /// Returns an iterator on slice groups using the *linear search* method,
+    /// a new group starts at every element for which `is_head` returns `true`.
+    ///
+    /// ```rust
+    /// use slice_group_by::GroupBy;
+    ///
+    /// let slice = &[1, 2, 0, 3, 0, 0, 4];
+    ///
+    /// let mut iter = slice.linear_group_starting_with(|&x| x == 0);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[0, 3][..]));
+    /// assert_eq!(iter.next(), Some(&[0][..]));
+    /// assert_eq!(iter.next(), Some(&[0, 4][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn linear_group_starting_with<P>(&self, is_head: P) -> LinearGroupStartingWith<'_, T, P>
+    where P: FnMut(&T) -> bool;
+
+    /// Returns an iterator on slice groups using the *linear search* method,
+    /// a group ends at every element for which `is_tail` returns `true`.
+    fn linear_group_ending_with<P>(&self, is_tail: P) -> LinearGroupEndingWith<'_, T, P>
+    where P: FnMut(&T) -> bool;
+
+    /// Returns an iterator on sessions of a slice sorted by key, a new session starting
+    /// wherever the difference between the keys of two neighbouring elements is greater
+    /// than `max_gap`. It uses *linear search* to iterate over groups.
+    ///
+    /// The gaps between the sessions can be retrieved using [`GapGroupByKey::with_gaps`].
+    ///
+    /// [`GapGroupByKey::with_gaps`]: struct.GapGroupByKey.html#method.with_gaps
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GapStats};
+    ///
+    /// let events = &[(1, 'a'), (3, 'b'), (10, 'c'), (11, 'd'), (30, 'e')];
+    ///
+    /// let mut iter = events.gap_group_by_key(|e| e.0, 5);
+    ///
+    /// assert_eq!(iter.next(), Some(&events[..2]));
+    /// assert_eq!(iter.next(), Some(&events[2..4]));
+    /// assert_eq!(iter.next(), Some(&events[4..]));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// let gaps: Vec<_> = events.gap_group_by_key(|e| e.0, 5).with_gaps().map(|(g, _)| g).collect();
+    /// assert_eq!(gaps, vec![
+    ///     GapStats { before: None, after: Some(7) },
+    ///     GapStats { before: Some(7), after: Some(19) },
+    ///     GapStats { before: Some(19), after: None },
+    /// ]);
+    /// ```
+    fn gap_group_by_key<F, K, D>(&self, func: F, max_gap: D) -> GapGroupByKey<'_, T, F, D>
+    where F: FnMut(&T) -> K,
+          K: Sub<Output = D>,
+          D: PartialOrd;
+
     /// Returns an iterator on slice groups based that will use the given function to generate keys
     /// and determine groups based on them. It uses *binary search* to iterate over groups.
     ///
@@ -362,6 +661,121 @@ pub trait GroupBy<T>
     /// [`PartialEq::eq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html#tymethod.eq
     fn exponential_group(&self) -> ExponentialGroup<T>
     where T: PartialEq;
+
+    /// Returns an iterator on slice groups based that will use the given function to generate keys
+    /// and determine groups based on them. It uses *linear search* to iterate over groups and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<LinearGroupByKey<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on slice groups using the *linear search* method and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<LinearGroupBy<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on slice groups based that will use the given function to generate keys
+    /// and determine groups based on them. It uses *binary search* to iterate over groups and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn binary_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<BinaryGroupByKey<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on slice groups using the *binary search* method and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn binary_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<BinaryGroupBy<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on slice groups based that will use the given function to generate keys
+    /// and determine groups based on them. It uses *exponential search* to iterate over groups and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn exponential_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<ExponentialGroupByKey<'_, T, F>>
+    where F: Fn(&T) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on slice groups using the *exponential search* method and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn exponential_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<ExponentialGroupBy<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on the fixed-width buckets of a slice sorted by key, the buckets
+    /// being aligned on `origin` and spanning from the first to the last element.
+    /// It uses *exponential search* to find the end of every bucket.
+    ///
+    /// Every bucket is returned along with its start, the buckets without
+    /// any element are returned with an empty slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not strictly positive or if the bucket of the first
+    /// element starts below the minimum of the key type.
+    ///
+    /// ```rust
+    /// use slice_group_by::GroupBy;
+    ///
+    /// let timestamps = &[1_000, 1_030, 1_130, 1_300];
+    ///
+    /// let mut iter = timestamps.bucket_group_by_key(|t| *t, 0, 100);
+    ///
+    /// assert_eq!(iter.next(), Some((1_000, &[1_000, 1_030][..])));
+    /// assert_eq!(iter.next(), Some((1_100, &[1_130][..])));
+    /// assert_eq!(iter.next(), Some((1_200, &[][..])));
+    /// assert_eq!(iter.next(), Some((1_300, &[1_300][..])));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn bucket_group_by_key<F, K>(&self, func: F, origin: K, width: K) -> BucketGroupByKey<'_, T, F, K>
+    where F: FnMut(&T) -> K,
+          K: BucketKey;
+
+    /// Returns an iterator on the fixed-width buckets of a slice sorted by key, the buckets
+    /// being aligned on `origin` and overlapping the given `range` of keys.
+    /// It uses *exponential search* to find the end of every bucket.
+    ///
+    /// The elements outside of the range are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is not strictly positive or if the bucket of the range
+    /// start begins below the minimum of the key type.
+    fn bucket_group_by_key_in<F, K>(&self, func: F, origin: K, width: K, range: Range<K>) -> BucketGroupByKey<'_, T, F, K>
+    where F: FnMut(&T) -> K,
+          K: BucketKey;
 }
 
 /// A convenient trait to construct an iterator returning non-overlapping *mutable*
@@ -386,6 +800,44 @@ pub trait GroupByMut<T>
     fn linear_group_mut(&mut self) -> LinearGroupMut<T>
     where T: PartialEq;
 
+    /// Returns an iterator on *mutable* slice groups using the *linear search* method, the predicate
+    /// is given the first element of the current group instead of the previous element.
+    fn linear_group_by_anchor_mut<P>(&mut self, predicate: P) -> LinearGroupByAnchorMut<'_, T, P>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on *mutable* slice groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups, the predicate is given the key of the first element
+    /// of the current group instead of the key of the previous element.
+    fn linear_group_by_key_anchor_mut<F, K, P>(&mut self, func: F, predicate: P) -> LinearGroupByKeyAnchorMut<'_, T, F, P>
+    where F: FnMut(&T) -> K,
+          P: FnMut(&K, &K) -> bool;
+
+    /// Returns an iterator on *mutable* slice groups using the *linear search* method, the given
+    /// step function decides whether an element joins the current group by updating
+    /// a state that is reset to `init` at the start of every group.
+    fn linear_group_by_fold_mut<S, F>(&mut self, init: S, step: F) -> LinearGroupByFoldMut<'_, T, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, &T) -> bool;
+
+    /// Returns an iterator on *mutable* slice groups using the *linear search* method,
+    /// a new group starts at every element for which `is_head` returns `true`.
+    fn linear_group_starting_with_mut<P>(&mut self, is_head: P) -> LinearGroupStartingWithMut<'_, T, P>
+    where P: FnMut(&T) -> bool;
+
+    /// Returns an iterator on *mutable* slice groups using the *linear search* method,
+    /// a group ends at every element for which `is_tail` returns `true`.
+    fn linear_group_ending_with_mut<P>(&mut self, is_tail: P) -> LinearGroupEndingWithMut<'_, T, P>
+    where P: FnMut(&T) -> bool;
+
+    /// Returns an iterator on *mutable* sessions of a slice sorted by key, a new session
+    /// starting wherever the difference between the keys of two neighbouring elements
+    /// is greater than `max_gap`. It uses *linear search* to iterate over groups.
+    fn gap_group_by_key_mut<F, K, D>(&mut self, func: F, max_gap: D) -> GapGroupByKeyMut<'_, T, F, D>
+    where F: FnMut(&T) -> K,
+          K: Sub<Output = D>,
+          D: PartialOrd;
+
     /// Returns an iterator on *mutable* slice groups based that will use the given function
     /// to generate keys and determine groups based on them. It uses *binary search*
     /// to iterate over groups.
@@ -439,6 +891,78 @@ pub trait GroupByMut<T>
     /// [`PartialEq::eq`]: https://doc.rust-lang.org/std/cmp/trait.PartialEq.html#tymethod.eq
     fn exponential_group_mut(&mut self) -> ExponentialGroupMut<T>
     where T: PartialEq;
+
+    /// Returns an iterator on *mutable* slice groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups and splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<LinearGroupByKeyMut<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on *mutable* slice groups using the *linear search* method and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<LinearGroupByMut<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on *mutable* slice groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *binary search*
+    /// to iterate over groups and splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn binary_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<BinaryGroupByKeyMut<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on *mutable* slice groups using the *binary search* method and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn binary_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<BinaryGroupByMut<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool;
+
+    /// Returns an iterator on *mutable* slice groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *exponential search*
+    /// to iterate over groups and splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn exponential_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<ExponentialGroupByKeyMut<'_, T, F>>
+    where F: Fn(&T) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on *mutable* slice groups using the *exponential search* method and
+    /// splits the groups longer than `max_len` into consecutive chunks.
+    ///
+    /// The predicate function should implement an order consistent with
+    /// the sort order of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn exponential_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<ExponentialGroupByMut<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool;
 }
 
 impl<T> GroupBy<T> for [T]
@@ -462,6 +986,46 @@ impl<T> GroupBy<T> for [T]
         LinearGroup::new(self)
     }
 
+    fn linear_group_by_anchor<P>(&self, predicate: P) -> LinearGroupByAnchor<'_, T, P>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        LinearGroupByAnchor::new(self, predicate)
+    }
+
+    fn linear_group_by_key_anchor<F, K, P>(&self, func: F, predicate: P) -> LinearGroupByKeyAnchor<'_, T, F, P>
+    where F: FnMut(&T) -> K,
+          P: FnMut(&K, &K) -> bool,
+    {
+        LinearGroupByKeyAnchor::new(self, func, predicate)
+    }
+
+    fn linear_group_by_fold<S, F>(&self, init: S, step: F) -> LinearGroupByFold<'_, T, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, &T) -> bool,
+    {
+        LinearGroupByFold::new(self, init, step)
+    }
+
+    fn linear_group_starting_with<P>(&self, is_head: P) -> LinearGroupStartingWith<'_, T, P>
+    where P: FnMut(&T) -> bool,
+    {
+        LinearGroupStartingWith::new(self, is_head)
+    }
+
+    fn linear_group_ending_with<P>(&self, is_tail: P) -> LinearGroupEndingWith<'_, T, P>
+    where P: FnMut(&T) -> bool,
+    {
+        LinearGroupEndingWith::new(self, is_tail)
+    }
+
+    fn gap_group_by_key<F, K, D>(&self, func: F, max_gap: D) -> GapGroupByKey<'_, T, F, D>
+    where F: FnMut(&T) -> K,
+          K: Sub<Output = D>,
+          D: PartialOrd,
+    {
+        GapGroupByKey::new(self, func, max_gap)
+    }
+
     fn binary_group_by_key<F, K>(&self, func: F) -> BinaryGroupByKey<T, F>
     where F: FnMut(&T) -> K,
           K: PartialEq
@@ -499,6 +1063,59 @@ impl<T> GroupBy<T> for [T]
     {
         ExponentialGroup::new(self)
     }
+
+    fn linear_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<LinearGroupByKey<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(LinearGroupByKey::new(self, func), max_len)
+    }
+
+    fn linear_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<LinearGroupBy<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        GroupMaxLen::new(LinearGroupBy::new(self, predicate), max_len)
+    }
+
+    fn binary_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<BinaryGroupByKey<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(BinaryGroupByKey::new(self, func), max_len)
+    }
+
+    fn binary_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<BinaryGroupBy<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        GroupMaxLen::new(BinaryGroupBy::new(self, predicate), max_len)
+    }
+
+    fn exponential_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<ExponentialGroupByKey<'_, T, F>>
+    where F: Fn(&T) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(ExponentialGroupByKey::new(self, func), max_len)
+    }
+
+    fn exponential_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<ExponentialGroupBy<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        GroupMaxLen::new(ExponentialGroupBy::new(self, predicate), max_len)
+    }
+
+    fn bucket_group_by_key<F, K>(&self, func: F, origin: K, width: K) -> BucketGroupByKey<'_, T, F, K>
+    where F: FnMut(&T) -> K,
+          K: BucketKey,
+    {
+        BucketGroupByKey::new(self, func, origin, width)
+    }
+
+    fn bucket_group_by_key_in<F, K>(&self, func: F, origin: K, width: K, range: Range<K>) -> BucketGroupByKey<'_, T, F, K>
+    where F: FnMut(&T) -> K,
+          K: BucketKey,
+    {
+        BucketGroupByKey::with_range(self, func, origin, width, range)
+    }
 }
 
 impl<T> GroupByMut<T> for [T]
@@ -522,6 +1139,46 @@ impl<T> GroupByMut<T> for [T]
         LinearGroupMut::new(self)
     }
 
+    fn linear_group_by_anchor_mut<P>(&mut self, predicate: P) -> LinearGroupByAnchorMut<'_, T, P>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        LinearGroupByAnchorMut::new(self, predicate)
+    }
+
+    fn linear_group_by_key_anchor_mut<F, K, P>(&mut self, func: F, predicate: P) -> LinearGroupByKeyAnchorMut<'_, T, F, P>
+    where F: FnMut(&T) -> K,
+          P: FnMut(&K, &K) -> bool,
+    {
+        LinearGroupByKeyAnchorMut::new(self, func, predicate)
+    }
+
+    fn linear_group_by_fold_mut<S, F>(&mut self, init: S, step: F) -> LinearGroupByFoldMut<'_, T, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, &T) -> bool,
+    {
+        LinearGroupByFoldMut::new(self, init, step)
+    }
+
+    fn linear_group_starting_with_mut<P>(&mut self, is_head: P) -> LinearGroupStartingWithMut<'_, T, P>
+    where P: FnMut(&T) -> bool,
+    {
+        LinearGroupStartingWithMut::new(self, is_head)
+    }
+
+    fn linear_group_ending_with_mut<P>(&mut self, is_tail: P) -> LinearGroupEndingWithMut<'_, T, P>
+    where P: FnMut(&T) -> bool,
+    {
+        LinearGroupEndingWithMut::new(self, is_tail)
+    }
+
+    fn gap_group_by_key_mut<F, K, D>(&mut self, func: F, max_gap: D) -> GapGroupByKeyMut<'_, T, F, D>
+    where F: FnMut(&T) -> K,
+          K: Sub<Output = D>,
+          D: PartialOrd,
+    {
+        GapGroupByKeyMut::new(self, func, max_gap)
+    }
+
     fn binary_group_by_key_mut<F, K>(&mut self, func: F) -> BinaryGroupByKeyMut<T, F>
     where F: FnMut(&T) -> K,
           K: PartialEq
@@ -559,6 +1216,266 @@ impl<T> GroupByMut<T> for [T]
     {
         ExponentialGroupMut::new(self)
     }
+
+    fn linear_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<LinearGroupByKeyMut<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(LinearGroupByKeyMut::new(self, func), max_len)
+    }
+
+    fn linear_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<LinearGroupByMut<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        GroupMaxLen::new(LinearGroupByMut::new(self, predicate), max_len)
+    }
+
+    fn binary_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<BinaryGroupByKeyMut<'_, T, F>>
+    where F: FnMut(&T) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(BinaryGroupByKeyMut::new(self, func), max_len)
+    }
+
+    fn binary_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<BinaryGroupByMut<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        GroupMaxLen::new(BinaryGroupByMut::new(self, predicate), max_len)
+    }
+
+    fn exponential_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<ExponentialGroupByKeyMut<'_, T, F>>
+    where F: Fn(&T) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(ExponentialGroupByKeyMut::new(self, func), max_len)
+    }
+
+    fn exponential_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<ExponentialGroupByMut<'_, T, P>>
+    where P: FnMut(&T, &T) -> bool,
+    {
+        GroupMaxLen::new(ExponentialGroupByMut::new(self, predicate), max_len)
+    }
+}
+
+/// The method used to search for the end of a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchMode {
+    /// Compares every element with its neighbour, see [`LinearGroupBy`].
+    ///
+    /// [`LinearGroupBy`]: struct.LinearGroupBy.html
+    Linear,
+    /// Binary searches the end of a group, see [`BinaryGroupBy`].
+    ///
+    /// [`BinaryGroupBy`]: struct.BinaryGroupBy.html
+    Binary,
+    /// Exponentially searches the end of a group, see [`ExponentialGroupBy`].
+    ///
+    /// [`ExponentialGroupBy`]: struct.ExponentialGroupBy.html
+    Exponential,
+}
+
+/// A convenient trait to construct adapters over the groups returned by the
+/// group iterators of this crate.
+///
+/// # Safety
+///
+/// Implementors must return non-overlapping groups of the same slice and two groups
+/// returned one after the other from the same end must be adjacent in memory.
+pub unsafe trait GroupIterator: DoubleEndedIterator + Sized
+where Self::Item: Group,
+{
+    /// Returns an iterator that joins contiguous whole groups into batches
+    /// of at most `max_len` elements.
+    ///
+    /// A group longer than `max_len` is returned alone by default, see [`GroupBatches::oversized`].
+    ///
+    /// [`GroupBatches::oversized`]: struct.GroupBatches.html#method.oversized
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GroupIterator};
+    ///
+    /// let slice = &[1, 1, 2, 3, 3, 3, 4, 5, 5];
+    ///
+    /// let mut iter = slice.linear_group().group_batches(4);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[3, 3, 3, 4][..]));
+    /// assert_eq!(iter.next(), Some(&[5, 5][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn group_batches(self, max_len: usize) -> GroupBatches<Self, UnitWeight<Self::Item>>
+    where Self::Item: GroupSlice,
+    {
+        GroupBatches::new(self, max_len, |_| 1)
+    }
+
+    /// Returns an iterator that joins contiguous whole groups into batches that are not
+    /// heavier than `max_weight`, the weight of a group being the sum of the weights
+    /// of its elements.
+    fn group_batches_by_weight<W>(self, max_weight: usize, weight: W) -> GroupBatches<Self, W>
+    where Self::Item: GroupSlice,
+          W: FnMut(&<Self::Item as GroupSlice>::Elem) -> usize,
+    {
+        GroupBatches::new(self, max_weight, weight)
+    }
+
+    /// Returns an iterator that splits the groups longer than `max_len` into consecutive
+    /// chunks of at most `max_len` elements, each chunk comes with a [`GroupPart`] that
+    /// indicates whether it is the start or a continuation of a group.
+    ///
+    /// [`GroupPart`]: enum.GroupPart.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GroupIterator, GroupPart};
+    ///
+    /// let slice = &[1, 1, 1, 1, 1, 2, 3, 3];
+    ///
+    /// let mut iter = slice.linear_group().max_len(2);
+    ///
+    /// assert_eq!(iter.next(), Some((GroupPart::Start, &[1, 1][..])));
+    /// assert_eq!(iter.next(), Some((GroupPart::Continuation, &[1, 1][..])));
+    /// assert_eq!(iter.next(), Some((GroupPart::Continuation, &[1][..])));
+    /// assert_eq!(iter.next(), Some((GroupPart::Start, &[2][..])));
+    /// assert_eq!(iter.next(), Some((GroupPart::Start, &[3, 3][..])));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn max_len(self, max_len: usize) -> GroupMaxLen<Self> {
+        GroupMaxLen::new(self, max_len)
+    }
+
+    /// Returns an iterator over overlapping windows of `N` consecutive groups.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GroupIterator};
+    ///
+    /// let slice = &[1, 1, 2, 3, 3];
+    ///
+    /// let mut iter = slice.linear_group().group_windows::<2>();
+    ///
+    /// assert_eq!(iter.next(), Some([&[1, 1][..], &[2][..]]));
+    /// assert_eq!(iter.next(), Some([&[2][..], &[3, 3][..]]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn group_windows<const N: usize>(self) -> GroupWindows<Self, N>
+    where Self::Item: Copy,
+    {
+        GroupWindows::new(self)
+    }
+
+    /// Returns an iterator over overlapping pairs of consecutive groups.
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GroupIterator};
+    ///
+    /// let days = &[(1, 10), (1, 5), (2, 20), (3, 12)];
+    ///
+    /// let deltas: Vec<i32> = days.linear_group_by_key(|x| x.0)
+    ///     .group_pairs()
+    ///     .map(|(prev, next)| {
+    ///         let prev: i32 = prev.iter().map(|x| x.1).sum();
+    ///         let next: i32 = next.iter().map(|x| x.1).sum();
+    ///         next - prev
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(deltas, vec![5, -8]);
+    /// ```
+    fn group_pairs(self) -> GroupPairs<Self>
+    where Self::Item: Copy,
+    {
+        GroupPairs::new(self)
+    }
+
+    /// Returns a lending iterator over overlapping pairs of consecutive *mutable* groups,
+    /// the previous group being given immutably along with the current one mutably.
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupByMut, GroupIterator};
+    ///
+    /// let slice = &mut [1, 1, 4, 4, 6];
+    ///
+    /// let mut pairs = slice.linear_group_mut().group_pairs_mut();
+    /// while let Some((prev, current)) = pairs.next() {
+    ///     let delta = current[0] - prev[0];
+    ///     current.iter_mut().for_each(|x| *x = delta);
+    /// }
+    ///
+    /// // the previous group has already been modified
+    /// assert_eq!(slice, &[1, 1, 3, 3, 3]);
+    /// ```
+    fn group_pairs_mut<'a, T: 'a>(self) -> GroupPairsMut<'a, T, Self>
+    where Self: Iterator<Item = &'a mut [T]>,
+    {
+        GroupPairsMut::new(self)
+    }
+
+    /// Returns an iterator that merges adjacent groups while the predicate, given two
+    /// neighbouring groups in the order of the slice, returns `true`.
+    ///
+    /// When iterating forward the first group given to the predicate is the one
+    /// merged so far, when iterating backward it is the second one.
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GroupIterator};
+    ///
+    /// let slice = &[1, 1, 2, 5, 6, 6, 10];
+    ///
+    /// let mut iter = slice.linear_group()
+    ///                     .merge_adjacent_groups_by(|prev, next| next[0] - prev[prev.len() - 1] <= 1);
+    ///
+    /// assert_eq!(iter.next(), Some(&[1, 1, 2][..]));
+    /// assert_eq!(iter.next(), Some(&[5, 6, 6][..]));
+    /// assert_eq!(iter.next(), Some(&[10][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn merge_adjacent_groups_by<P>(self, predicate: P) -> MergeAdjacentGroupsBy<Self, P>
+    where Self::Item: GroupSlice,
+          P: FnMut(&[<Self::Item as GroupSlice>::Elem], &[<Self::Item as GroupSlice>::Elem]) -> bool,
+    {
+        MergeAdjacentGroupsBy::new(self, predicate)
+    }
+
+    /// Returns an iterator that gives every group along with an iterator on its inner groups,
+    /// determined by the given key function and searched using the given [`SearchMode`].
+    ///
+    /// [`SearchMode`]: enum.SearchMode.html
+    ///
+    /// ```rust
+    /// use slice_group_by::{GroupBy, GroupIterator, SearchMode};
+    ///
+    /// let slice = &[(1, 'a'), (1, 'a'), (1, 'b'), (2, 'b'), (2, 'b')];
+    ///
+    /// let mut iter = slice.binary_group_by_key(|x| x.0)
+    ///                     .then_group_by_key(SearchMode::Linear, |x: &(i32, char)| x.1);
+    ///
+    /// let (group, mut inner) = iter.next().unwrap();
+    /// assert_eq!(group, &slice[..3]);
+    /// assert_eq!(inner.next(), Some(&slice[..2]));
+    /// assert_eq!(inner.next(), Some(&slice[2..3]));
+    /// assert_eq!(inner.next(), None);
+    ///
+    /// let (group, mut inner) = iter.next().unwrap();
+    /// assert_eq!(group, &slice[3..]);
+    /// assert_eq!(inner.next(), Some(&slice[3..]));
+    /// assert_eq!(inner.next(), None);
+    ///
+    /// assert!(iter.next().is_none());
+    /// ```
+    fn then_group_by_key<'a, T: 'a, F, K>(self, mode: SearchMode, func: F) -> ThenGroupByKey<Self, F>
+    where Self: Iterator<Item = &'a [T]>,
+          F: Fn(&T) -> K + Clone,
+          K: PartialEq,
+    {
+        ThenGroupByKey::new(self, mode, func)
+    }
 }
 
 /// A convenient trait to construct an iterator returning non-overlapping `str` slices
@@ -581,6 +1498,193 @@ pub trait StrGroupBy
     ///
     /// [`PartialEq::eq`]: https://doc.rust-lang.org/std/primitive.char.html#impl-PartialEq%3Cchar%3E
     fn linear_group(&self) -> LinearStrGroup;
+
+    /// Returns an iterator on `str` groups using the *linear search* method, the predicate
+    /// is given the first `char` of the current group instead of the previous `char`.
+    fn linear_group_by_anchor<P>(&self, predicate: P) -> LinearStrGroupByAnchor<'_, P>
+    where P: FnMut(char, char) -> bool;
+
+    /// Returns an iterator on `str` groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups, the predicate is given the key of the first `char`
+    /// of the current group instead of the key of the previous `char`.
+    fn linear_group_by_key_anchor<F, K, P>(&self, func: F, predicate: P) -> LinearStrGroupByKeyAnchor<'_, F, P>
+    where F: FnMut(char) -> K,
+          P: FnMut(&K, &K) -> bool;
+
+    /// Returns an iterator on `str` groups using the *linear search* method, the given
+    /// step function decides whether a `char` joins the current group by updating
+    /// a state that is reset to `init` at the start of every group.
+    fn linear_group_by_fold<S, F>(&self, init: S, step: F) -> LinearStrGroupByFold<'_, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, char) -> bool;
+
+    /// Returns an iterator on `str` groups using the *linear search* method,
+    /// a new group starts at every `char` for which `is_head` returns `true`.
+    fn linear_group_starting_with<P>(&self, is_head: P) -> LinearStrGroupStartingWith<'_, P>
+    where P: FnMut(char) -> bool;
+
+    /// Returns an iterator on `str` groups using the *linear search* method,
+    /// a group ends at every `char` for which `is_tail` returns `true`.
+    fn linear_group_ending_with<P>(&self, is_tail: P) -> LinearStrGroupEndingWith<'_, P>
+    where P: FnMut(char) -> bool;
+
+    /// Returns an iterator on `str` groups using the *linear search* method over the extended
+    /// grapheme clusters of the `str`, therefore the groups never split a grapheme cluster apart.
+    ///
+    /// It gives two contiguous grapheme clusters to the predicate function.
+    /// This method is only available with the `unicode-segmentation` feature.
+    ///
+    /// ```rust
+    /// use slice_group_by::StrGroupBy;
+    ///
+    /// let string = "e\u{301}e\u{301}eaa";
+    ///
+    /// let mut iter = string.linear_group_by_grapheme(|a, b| a == b);
+    ///
+    /// assert_eq!(iter.next(), Some("e\u{301}e\u{301}"));
+    /// assert_eq!(iter.next(), Some("e"));
+    /// assert_eq!(iter.next(), Some("aa"));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    fn linear_group_by_grapheme<P>(&self, predicate: P) -> LinearStrGroupByGrapheme<'_, P>
+    where P: FnMut(&str, &str) -> bool;
+
+    /// Returns an iterator on `str` groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups and splits the groups longer than `max_len` `char`s
+    /// into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<LinearStrGroupByKey<'_, F>>
+    where F: FnMut(char) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on `str` groups using the *linear search* method and
+    /// splits the groups longer than `max_len` `char`s into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<LinearStrGroupBy<'_, P>>
+    where P: FnMut(char, char) -> bool;
+
+    /// Returns an iterator on `str` groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups and returns them along with their byte and `char` ranges,
+    /// their line and their column.
+    fn linear_group_by_key_with_spans<F, K>(&self, func: F) -> StrGroupSpans<'_, LinearStrGroupByKey<'_, F>>
+    where F: FnMut(char) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on `str` groups using the *linear search* method and
+    /// returns them along with their byte and `char` ranges, their line and their column.
+    ///
+    /// ```rust
+    /// use slice_group_by::StrGroupBy;
+    ///
+    /// let string = "let x\n  = 1;";
+    ///
+    /// let mut iter = string.linear_group_by_with_spans(|a, b| a.is_whitespace() == b.is_whitespace());
+    ///
+    /// let span = iter.next_back().unwrap();
+    /// assert_eq!(span.text, "1;");
+    /// assert_eq!(span.bytes, 10..12);
+    /// assert_eq!((span.line, span.column), (1, 4));
+    ///
+    /// let span = iter.find(|span| span.text == "=").unwrap();
+    /// assert_eq!(span.chars, 8..9);
+    /// assert_eq!((span.line, span.column), (1, 2));
+    /// ```
+    fn linear_group_by_with_spans<P>(&self, predicate: P) -> StrGroupSpans<'_, LinearStrGroupBy<'_, P>>
+    where P: FnMut(char, char) -> bool;
+
+    /// Returns an iterator on `str` groups based on the [`PartialEq::eq`] method of `char`,
+    /// it uses *linear search* to iterate over groups and returns them along with their
+    /// byte and `char` ranges, their line and their column.
+    ///
+    /// [`PartialEq::eq`]: https://doc.rust-lang.org/std/primitive.char.html#impl-PartialEq%3Cchar%3E
+    fn linear_group_with_spans(&self) -> StrGroupSpans<'_, LinearStrGroup<'_>>;
+
+    /// Returns an iterator on groups of whole lines based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups.
+    ///
+    /// The function is given every line without its `"\n"` or `"\r\n"` line terminator,
+    /// the groups contain the line terminators of their lines.
+    ///
+    /// ```rust
+    /// use slice_group_by::StrGroupBy;
+    ///
+    /// let string = "a 1\na 2\r\nb 3";
+    ///
+    /// let mut iter = string.lines_group_by_key(|line| line.chars().next());
+    ///
+    /// assert_eq!(iter.next(), Some("a 1\na 2\r\n"));
+    /// assert_eq!(iter.next(), Some("b 3"));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn lines_group_by_key<F, K>(&self, func: F) -> LinesGroupByKey<'_, F>
+    where F: FnMut(&str) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on the runs of `char`s of the same [`CharClass`] along with their class,
+    /// the class of a `char` being given by [`CharClass::of`]. It uses *linear search* to iterate
+    /// over the runs.
+    ///
+    /// The classes follow the Unicode 14.0.0 Character Database.
+    ///
+    /// [`CharClass`]: enum.CharClass.html
+    /// [`CharClass::of`]: enum.CharClass.html#method.of
+    ///
+    /// ```rust
+    /// use slice_group_by::{StrGroupBy, CharClass};
+    ///
+    /// let string = "x += 42;";
+    ///
+    /// let mut iter = string.str_tokens();
+    ///
+    /// assert_eq!(iter.next(), Some((CharClass::Alphabetic, "x")));
+    /// assert_eq!(iter.next(), Some((CharClass::Whitespace, " ")));
+    /// assert_eq!(iter.next(), Some((CharClass::Symbol, "+=")));
+    /// assert_eq!(iter.next(), Some((CharClass::Whitespace, " ")));
+    /// assert_eq!(iter.next(), Some((CharClass::Numeric, "42")));
+    /// assert_eq!(iter.next(), Some((CharClass::Punctuation, ";")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn str_tokens(&self) -> StrTokens<'_, fn(char) -> CharClass>;
+
+    /// Returns an iterator on the runs of `char`s of the same [`CharClass`] along with their class,
+    /// the class of a `char` being given by the classifier function. It uses *linear search*
+    /// to iterate over the runs.
+    ///
+    /// [`CharClass`]: enum.CharClass.html
+    fn str_tokens_by<F>(&self, classifier: F) -> StrTokens<'_, F>
+    where F: FnMut(char) -> CharClass;
+
+    /// Returns an iterator on the runs of the same Unicode [`Script`] along with their script,
+    /// the `Common` and `Inherited` `char`s joining the run of the preceding `char`,
+    /// or the run of the following `char` at the start of the `str`, as described in [UAX #24].
+    ///
+    /// [`Script`]: enum.Script.html
+    /// [UAX #24]: https://www.unicode.org/reports/tr24/
+    ///
+    /// ```rust
+    /// use slice_group_by::{StrGroupBy, Script};
+    ///
+    /// let string = "Hello, мир! 你好";
+    ///
+    /// let mut iter = string.script_runs();
+    ///
+    /// assert_eq!(iter.next(), Some((Script::Latin, "Hello, ")));
+    /// assert_eq!(iter.next(), Some((Script::Cyrillic, "мир! ")));
+    /// assert_eq!(iter.next(), Some((Script::Han, "你好")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn script_runs(&self) -> ScriptRuns<'_>;
 }
 
 /// A convenient trait to construct an iterator returning non-overlapping *mutable* `str` slices
@@ -603,6 +1707,67 @@ pub trait StrGroupByMut
     ///
     /// [`PartialEq::eq`]: https://doc.rust-lang.org/std/primitive.char.html#impl-PartialEq%3Cchar%3E
     fn linear_group_mut(&mut self) -> LinearStrGroupMut;
+
+    /// Returns an iterator on *mutable* `str` groups using the *linear search* method, the predicate
+    /// is given the first `char` of the current group instead of the previous `char`.
+    fn linear_group_by_anchor_mut<P>(&mut self, predicate: P) -> LinearStrGroupByAnchorMut<'_, P>
+    where P: FnMut(char, char) -> bool;
+
+    /// Returns an iterator on *mutable* `str` groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups, the predicate is given the key of the first `char`
+    /// of the current group instead of the key of the previous `char`.
+    fn linear_group_by_key_anchor_mut<F, K, P>(&mut self, func: F, predicate: P) -> LinearStrGroupByKeyAnchorMut<'_, F, P>
+    where F: FnMut(char) -> K,
+          P: FnMut(&K, &K) -> bool;
+
+    /// Returns an iterator on *mutable* `str` groups using the *linear search* method, the given
+    /// step function decides whether a `char` joins the current group by updating
+    /// a state that is reset to `init` at the start of every group.
+    fn linear_group_by_fold_mut<S, F>(&mut self, init: S, step: F) -> LinearStrGroupByFoldMut<'_, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, char) -> bool;
+
+    /// Returns an iterator on *mutable* `str` groups using the *linear search* method,
+    /// a new group starts at every `char` for which `is_head` returns `true`.
+    fn linear_group_starting_with_mut<P>(&mut self, is_head: P) -> LinearStrGroupStartingWithMut<'_, P>
+    where P: FnMut(char) -> bool;
+
+    /// Returns an iterator on *mutable* `str` groups using the *linear search* method,
+    /// a group ends at every `char` for which `is_tail` returns `true`.
+    fn linear_group_ending_with_mut<P>(&mut self, is_tail: P) -> LinearStrGroupEndingWithMut<'_, P>
+    where P: FnMut(char) -> bool;
+
+    /// Returns an iterator on *mutable* `str` groups using the *linear search* method over
+    /// the extended grapheme clusters of the `str`, therefore the groups never split
+    /// a grapheme cluster apart.
+    ///
+    /// It gives two contiguous grapheme clusters to the predicate function.
+    /// This method is only available with the `unicode-segmentation` feature.
+    #[cfg(feature = "unicode-segmentation")]
+    fn linear_group_by_grapheme_mut<P>(&mut self, predicate: P) -> LinearStrGroupByGraphemeMut<'_, P>
+    where P: FnMut(&str, &str) -> bool;
+
+    /// Returns an iterator on *mutable* `str` groups based that will use the given function
+    /// to generate keys and determine groups based on them. It uses *linear search*
+    /// to iterate over groups and splits the groups longer than `max_len` `char`s
+    /// into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<LinearStrGroupByKeyMut<'_, F>>
+    where F: FnMut(char) -> K,
+          K: PartialEq;
+
+    /// Returns an iterator on *mutable* `str` groups using the *linear search* method and
+    /// splits the groups longer than `max_len` `char`s into consecutive chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    fn linear_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<LinearStrGroupByMut<'_, P>>
+    where P: FnMut(char, char) -> bool;
 }
 
 impl StrGroupBy for str
@@ -623,6 +1788,96 @@ impl StrGroupBy for str
     fn linear_group(&self) -> LinearStrGroup {
         LinearStrGroup::new(self)
     }
+
+    fn linear_group_by_anchor<P>(&self, predicate: P) -> LinearStrGroupByAnchor<'_, P>
+    where P: FnMut(char, char) -> bool,
+    {
+        LinearStrGroupByAnchor::new(self, predicate)
+    }
+
+    fn linear_group_by_key_anchor<F, K, P>(&self, func: F, predicate: P) -> LinearStrGroupByKeyAnchor<'_, F, P>
+    where F: FnMut(char) -> K,
+          P: FnMut(&K, &K) -> bool,
+    {
+        LinearStrGroupByKeyAnchor::new(self, func, predicate)
+    }
+
+    fn linear_group_by_fold<S, F>(&self, init: S, step: F) -> LinearStrGroupByFold<'_, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, char) -> bool,
+    {
+        LinearStrGroupByFold::new(self, init, step)
+    }
+
+    fn linear_group_starting_with<P>(&self, is_head: P) -> LinearStrGroupStartingWith<'_, P>
+    where P: FnMut(char) -> bool,
+    {
+        LinearStrGroupStartingWith::new(self, is_head)
+    }
+
+    fn linear_group_ending_with<P>(&self, is_tail: P) -> LinearStrGroupEndingWith<'_, P>
+    where P: FnMut(char) -> bool,
+    {
+        LinearStrGroupEndingWith::new(self, is_tail)
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    fn linear_group_by_grapheme<P>(&self, predicate: P) -> LinearStrGroupByGrapheme<'_, P>
+    where P: FnMut(&str, &str) -> bool,
+    {
+        LinearStrGroupByGrapheme::new(self, predicate)
+    }
+
+    fn linear_group_by_key_max_len<F, K>(&self, func: F, max_len: usize) -> GroupMaxLen<LinearStrGroupByKey<'_, F>>
+    where F: FnMut(char) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(LinearStrGroupByKey::new(self, func), max_len)
+    }
+
+    fn linear_group_by_max_len<P>(&self, predicate: P, max_len: usize) -> GroupMaxLen<LinearStrGroupBy<'_, P>>
+    where P: FnMut(char, char) -> bool,
+    {
+        GroupMaxLen::new(LinearStrGroupBy::new(self, predicate), max_len)
+    }
+
+    fn linear_group_by_key_with_spans<F, K>(&self, func: F) -> StrGroupSpans<'_, LinearStrGroupByKey<'_, F>>
+    where F: FnMut(char) -> K,
+          K: PartialEq
+    {
+        StrGroupSpans::new(self, LinearStrGroupByKey::new(self, func))
+    }
+
+    fn linear_group_by_with_spans<P>(&self, predicate: P) -> StrGroupSpans<'_, LinearStrGroupBy<'_, P>>
+    where P: FnMut(char, char) -> bool,
+    {
+        StrGroupSpans::new(self, LinearStrGroupBy::new(self, predicate))
+    }
+
+    fn linear_group_with_spans(&self) -> StrGroupSpans<'_, LinearStrGroup<'_>> {
+        StrGroupSpans::new(self, LinearStrGroup::new(self))
+    }
+
+    fn lines_group_by_key<F, K>(&self, func: F) -> LinesGroupByKey<'_, F>
+    where F: FnMut(&str) -> K,
+          K: PartialEq
+    {
+        LinesGroupByKey::new(self, func)
+    }
+
+    fn str_tokens(&self) -> StrTokens<'_, fn(char) -> CharClass> {
+        StrTokens::new(self, CharClass::of)
+    }
+
+    fn str_tokens_by<F>(&self, classifier: F) -> StrTokens<'_, F>
+    where F: FnMut(char) -> CharClass,
+    {
+        StrTokens::new(self, classifier)
+    }
+
+    fn script_runs(&self) -> ScriptRuns<'_> {
+        ScriptRuns::new(self)
+    }
 }
 
 impl StrGroupByMut for str
@@ -643,4 +1898,56 @@ impl StrGroupByMut for str
     fn linear_group_mut(&mut self) -> LinearStrGroupMut {
         LinearStrGroupMut::new(self)
     }
+
+    fn linear_group_by_anchor_mut<P>(&mut self, predicate: P) -> LinearStrGroupByAnchorMut<'_, P>
+    where P: FnMut(char, char) -> bool,
+    {
+        LinearStrGroupByAnchorMut::new(self, predicate)
+    }
+
+    fn linear_group_by_key_anchor_mut<F, K, P>(&mut self, func: F, predicate: P) -> LinearStrGroupByKeyAnchorMut<'_, F, P>
+    where F: FnMut(char) -> K,
+          P: FnMut(&K, &K) -> bool,
+    {
+        LinearStrGroupByKeyAnchorMut::new(self, func, predicate)
+    }
+
+    fn linear_group_by_fold_mut<S, F>(&mut self, init: S, step: F) -> LinearStrGroupByFoldMut<'_, S, F>
+    where S: Clone,
+          F: FnMut(&mut S, char) -> bool,
+    {
+        LinearStrGroupByFoldMut::new(self, init, step)
+    }
+
+    fn linear_group_starting_with_mut<P>(&mut self, is_head: P) -> LinearStrGroupStartingWithMut<'_, P>
+    where P: FnMut(char) -> bool,
+    {
+        LinearStrGroupStartingWithMut::new(self, is_head)
+    }
+
+    fn linear_group_ending_with_mut<P>(&mut self, is_tail: P) -> LinearStrGroupEndingWithMut<'_, P>
+    where P: FnMut(char) -> bool,
+    {
+        LinearStrGroupEndingWithMut::new(self, is_tail)
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    fn linear_group_by_grapheme_mut<P>(&mut self, predicate: P) -> LinearStrGroupByGraphemeMut<'_, P>
+    where P: FnMut(&str, &str) -> bool,
+    {
+        LinearStrGroupByGraphemeMut::new(self, predicate)
+    }
+
+    fn linear_group_by_key_max_len_mut<F, K>(&mut self, func: F, max_len: usize) -> GroupMaxLen<LinearStrGroupByKeyMut<'_, F>>
+    where F: FnMut(char) -> K,
+          K: PartialEq
+    {
+        GroupMaxLen::new(LinearStrGroupByKeyMut::new(self, func), max_len)
+    }
+
+    fn linear_group_by_max_len_mut<P>(&mut self, predicate: P, max_len: usize) -> GroupMaxLen<LinearStrGroupByMut<'_, P>>
+    where P: FnMut(char, char) -> bool,
+    {
+        GroupMaxLen::new(LinearStrGroupByMut::new(self, predicate), max_len)
+    }
 }
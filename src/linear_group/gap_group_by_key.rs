@@ -0,0 +1,249 @@
+use std::ops::Sub;
+use std::{fmt, mem};
+
+/// The gaps between a session returned by the [`GapGroupByKeyStats`] iterator
+/// and its neighbouring sessions.
+///
+/// [`GapGroupByKeyStats`]: struct.GapGroupByKeyStats.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GapStats<D> {
+    /// The gap with the previous session, `None` for the first session.
+    pub before: Option<D>,
+    /// The gap with the next session, `None` for the last session.
+    pub after: Option<D>,
+}
+
+macro_rules! gap_group_by_key {
+    (struct $name:ident, struct $stats:ident, $elem:ty, $split:ident) => {
+        impl<'a, T: 'a, F, K, D> std::iter::Iterator for $name<'a, T, F, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.slice.is_empty() { return None }
+
+                let (func, max_gap) = (&mut self.func, &self.max_gap);
+                let len = 1 + self.slice.windows(2).take_while(|w| func(&w[1]) - func(&w[0]) <= *max_gap).count();
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len);
+                self.slice = tail;
+                Some(head)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, T: 'a, F, K, D> std::iter::DoubleEndedIterator for $name<'a, T, F, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.slice.is_empty() { return None }
+
+                let (func, max_gap) = (&mut self.func, &self.max_gap);
+                let len = 1 + self.slice.windows(2).rev().take_while(|w| func(&w[1]) - func(&w[0]) <= *max_gap).count();
+
+                let slice = mem::take(&mut self.slice);
+                let mid = slice.len() - len;
+                let (head, tail) = slice.$split(mid);
+                self.slice = head;
+                Some(tail)
+            }
+        }
+
+        impl<'a, T: 'a, F, K, D> std::iter::FusedIterator for $name<'a, T, F, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        { }
+
+        unsafe impl<'a, T: 'a, F, K, D> crate::GroupIterator for $name<'a, T, F, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, F, D: fmt::Debug> fmt::Debug for $name<'a, T, F, D> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("slice", &self.slice)
+                    .field("max_gap", &self.max_gap)
+                    .finish()
+            }
+        }
+
+        impl<'a, T: 'a, F, D> $name<'a, T, F, D> {
+            /// Returns an iterator that also gives the gaps between
+            /// every session and its neighbouring sessions.
+            pub fn with_gaps<K>(self) -> $stats<'a, T, F, K, D>
+            where F: FnMut(&T) -> K,
+            {
+                $stats { iter: self, front: None, back: None }
+            }
+        }
+
+        impl<'a, T: 'a, F, K, D> std::iter::Iterator for $stats<'a, T, F, K, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        {
+            type Item = (GapStats<D>, $elem);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let group = self.iter.next()?;
+                let (first, last) = (&group[0], &group[group.len() - 1]);
+                let func = &mut self.iter.func;
+
+                let before = self.front.take().map(|key| func(first) - key);
+                let after = match self.iter.slice.first() {
+                    Some(next) => Some(func(next) - func(last)),
+                    None => self.back.take().map(|key| key - func(last)),
+                };
+
+                self.front = Some(func(last));
+                Some((GapStats { before, after }, group))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.iter.size_hint()
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, T: 'a, F, K, D> std::iter::DoubleEndedIterator for $stats<'a, T, F, K, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let group = self.iter.next_back()?;
+                let (first, last) = (&group[0], &group[group.len() - 1]);
+                let func = &mut self.iter.func;
+
+                let after = self.back.take().map(|key| key - func(last));
+                let before = match self.iter.slice.last() {
+                    Some(prev) => Some(func(first) - func(prev)),
+                    None => self.front.take().map(|key| func(first) - key),
+                };
+
+                self.back = Some(func(first));
+                Some((GapStats { before, after }, group))
+            }
+        }
+
+        impl<'a, T: 'a, F, K, D> std::iter::FusedIterator for $stats<'a, T, F, K, D>
+        where F: FnMut(&T) -> K,
+              K: Sub<Output = D>,
+              D: PartialOrd,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, F, K, D: fmt::Debug> fmt::Debug for $stats<'a, T, F, K, D> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($stats))
+                    .field("slice", &self.iter.slice)
+                    .field("max_gap", &self.iter.max_gap)
+                    .finish()
+            }
+        }
+    }
+}
+
+/// An iterator that will return non-overlapping sessions in a slice sorted by key
+/// using *linear/sequential search*.
+///
+/// A new session starts wherever the difference between the keys
+/// of two neighbouring elements is greater than the maximum gap.
+pub struct GapGroupByKey<'a, T: 'a, F, D> {
+    slice: &'a [T],
+    func: F,
+    max_gap: D,
+}
+
+impl<'a, T: 'a, F, K, D> GapGroupByKey<'a, T, F, D>
+where F: FnMut(&T) -> K,
+      K: Sub<Output = D>,
+      D: PartialOrd,
+{
+    pub fn new(slice: &'a [T], func: F, max_gap: D) -> Self {
+        GapGroupByKey { slice, func, max_gap }
+    }
+}
+
+impl<'a, T: 'a, F, D> GapGroupByKey<'a, T, F, D> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+/// An iterator that will return non-overlapping sessions in a slice sorted by key
+/// along with the gaps between every session and its neighbouring sessions.
+pub struct GapGroupByKeyStats<'a, T: 'a, F, K, D> {
+    iter: GapGroupByKey<'a, T, F, D>,
+    front: Option<K>,
+    back: Option<K>,
+}
+
+gap_group_by_key!{ struct GapGroupByKey, struct GapGroupByKeyStats, &'a [T], split_at }
+
+/// An iterator that will return non-overlapping *mutable* sessions in a slice sorted by key
+/// using *linear/sequential search*.
+///
+/// A new session starts wherever the difference between the keys
+/// of two neighbouring elements is greater than the maximum gap.
+pub struct GapGroupByKeyMut<'a, T: 'a, F, D> {
+    slice: &'a mut [T],
+    func: F,
+    max_gap: D,
+}
+
+impl<'a, T: 'a, F, K, D> GapGroupByKeyMut<'a, T, F, D>
+where F: FnMut(&T) -> K,
+      K: Sub<Output = D>,
+      D: PartialOrd,
+{
+    pub fn new(slice: &'a mut [T], func: F, max_gap: D) -> Self {
+        GapGroupByKeyMut { slice, func, max_gap }
+    }
+}
+
+impl<'a, T: 'a, F, D> GapGroupByKeyMut<'a, T, F, D> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+/// An iterator that will return non-overlapping *mutable* sessions in a slice sorted by key
+/// along with the gaps between every session and its neighbouring sessions.
+pub struct GapGroupByKeyStatsMut<'a, T: 'a, F, K, D> {
+    iter: GapGroupByKeyMut<'a, T, F, D>,
+    front: Option<K>,
+    back: Option<K>,
+}
+
+gap_group_by_key!{ struct GapGroupByKeyMut, struct GapGroupByKeyStatsMut, &'a mut [T], split_at_mut }
@@ -1,5 +1,6 @@
 use std::iter::FusedIterator;
 use std::{mem, fmt, slice};
+use crate::GroupIterator;
 
 unsafe fn split_at_unchecked<T>(slice: &[T], mid: usize) -> (&[T], &[T]) {
     (slice.get_unchecked(..mid), slice.get_unchecked(mid..))
@@ -84,6 +85,8 @@ where
 
 impl<'a, T: 'a, P> FusedIterator for LinearGroupBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
 
+unsafe impl<'a, T: 'a, P> GroupIterator for LinearGroupBy<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
 impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for LinearGroupBy<'a, T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("LinearGroupBy").field("slice", &self.slice).finish()
@@ -159,6 +162,8 @@ where
 
 impl<'a, T: 'a, P> FusedIterator for LinearGroupByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {}
 
+unsafe impl<'a, T: 'a, P> GroupIterator for LinearGroupByMut<'a, T, P> where P: FnMut(&T, &T) -> bool {}
+
 impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for LinearGroupByMut<'a, T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("LinearGroupByMut").field("slice", &self.slice).finish()
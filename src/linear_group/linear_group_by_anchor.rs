@@ -0,0 +1,253 @@
+use std::{fmt, mem};
+
+macro_rules! group_by_anchor {
+    (struct $name:ident, $elem:ty, $split:ident) => {
+        impl<'a, T: 'a, P> std::iter::Iterator for $name<'a, T, P>
+        where P: FnMut(&T, &T) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (first, tail) = self.slice.split_first()?;
+
+                let predicate = &mut self.predicate;
+                let len = 1 + tail.iter().take_while(|&x| predicate(first, x)).count();
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len);
+                self.slice = tail;
+                Some(head)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+            }
+        }
+
+        impl<'a, T: 'a, P> std::iter::DoubleEndedIterator for $name<'a, T, P>
+        where P: FnMut(&T, &T) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let (last, head) = self.slice.split_last()?;
+
+                let predicate = &mut self.predicate;
+                let len = 1 + head.iter().rev().take_while(|&x| predicate(x, last)).count();
+
+                let slice = mem::take(&mut self.slice);
+                let mid = slice.len() - len;
+                let (head, tail) = slice.$split(mid);
+                self.slice = head;
+                Some(tail)
+            }
+        }
+
+        impl<'a, T: 'a, P> std::iter::FusedIterator for $name<'a, T, P>
+        where P: FnMut(&T, &T) -> bool,
+        { }
+
+        unsafe impl<'a, T: 'a, P> crate::GroupIterator for $name<'a, T, P>
+        where P: FnMut(&T, &T) -> bool,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for $name<'a, T, P> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name)).field("slice", &self.slice).finish()
+            }
+        }
+    }
+}
+
+macro_rules! group_by_key_anchor {
+    (struct $name:ident, $elem:ty, $split:ident) => {
+        impl<'a, T: 'a, F, K, P> std::iter::Iterator for $name<'a, T, F, P>
+        where F: FnMut(&T) -> K,
+              P: FnMut(&K, &K) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (first, tail) = self.slice.split_first()?;
+
+                let (func, predicate) = (&mut self.func, &mut self.predicate);
+                let anchor = func(first);
+                let len = 1 + tail.iter().take_while(|&x| predicate(&anchor, &func(x))).count();
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len);
+                self.slice = tail;
+                Some(head)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+            }
+        }
+
+        impl<'a, T: 'a, F, K, P> std::iter::DoubleEndedIterator for $name<'a, T, F, P>
+        where F: FnMut(&T) -> K,
+              P: FnMut(&K, &K) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let (last, head) = self.slice.split_last()?;
+
+                let (func, predicate) = (&mut self.func, &mut self.predicate);
+                let anchor = func(last);
+                let len = 1 + head.iter().rev().take_while(|&x| predicate(&func(x), &anchor)).count();
+
+                let slice = mem::take(&mut self.slice);
+                let mid = slice.len() - len;
+                let (head, tail) = slice.$split(mid);
+                self.slice = head;
+                Some(tail)
+            }
+        }
+
+        impl<'a, T: 'a, F, K, P> std::iter::FusedIterator for $name<'a, T, F, P>
+        where F: FnMut(&T) -> K,
+              P: FnMut(&K, &K) -> bool,
+        { }
+
+        unsafe impl<'a, T: 'a, F, K, P> crate::GroupIterator for $name<'a, T, F, P>
+        where F: FnMut(&T) -> K,
+              P: FnMut(&K, &K) -> bool,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, F, P> fmt::Debug for $name<'a, T, F, P> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name)).field("slice", &self.slice).finish()
+            }
+        }
+    }
+}
+
+/// An iterator that will return non-overlapping groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give the first element of the current group and the element
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the element preceding the group
+/// and the last element of the current group.
+pub struct LinearGroupByAnchor<'a, T: 'a, P> {
+    slice: &'a [T],
+    predicate: P,
+}
+
+impl<'a, T: 'a, P> LinearGroupByAnchor<'a, T, P>
+where P: FnMut(&T, &T) -> bool,
+{
+    pub fn new(slice: &'a [T], predicate: P) -> Self {
+        LinearGroupByAnchor { slice, predicate }
+    }
+}
+
+impl<'a, T: 'a, P> LinearGroupByAnchor<'a, T, P> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+group_by_anchor!{ struct LinearGroupByAnchor, &'a [T], split_at }
+
+/// An iterator that will return non-overlapping *mutable* groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give the first element of the current group and the element
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the element preceding the group
+/// and the last element of the current group.
+pub struct LinearGroupByAnchorMut<'a, T: 'a, P> {
+    slice: &'a mut [T],
+    predicate: P,
+}
+
+impl<'a, T: 'a, P> LinearGroupByAnchorMut<'a, T, P>
+where P: FnMut(&T, &T) -> bool,
+{
+    pub fn new(slice: &'a mut [T], predicate: P) -> Self {
+        LinearGroupByAnchorMut { slice, predicate }
+    }
+}
+
+impl<'a, T: 'a, P> LinearGroupByAnchorMut<'a, T, P> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+group_by_anchor!{ struct LinearGroupByAnchorMut, &'a mut [T], split_at_mut }
+
+/// An iterator that will return non-overlapping groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give an element to the given function, producing a key, and will give
+/// the key of the first element of the current group and the key of the element
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the key of the element preceding the group
+/// and the key of the last element of the current group.
+pub struct LinearGroupByKeyAnchor<'a, T: 'a, F, P> {
+    slice: &'a [T],
+    func: F,
+    predicate: P,
+}
+
+impl<'a, T: 'a, F, K, P> LinearGroupByKeyAnchor<'a, T, F, P>
+where F: FnMut(&T) -> K,
+      P: FnMut(&K, &K) -> bool,
+{
+    pub fn new(slice: &'a [T], func: F, predicate: P) -> Self {
+        LinearGroupByKeyAnchor { slice, func, predicate }
+    }
+}
+
+impl<'a, T: 'a, F, P> LinearGroupByKeyAnchor<'a, T, F, P> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+group_by_key_anchor!{ struct LinearGroupByKeyAnchor, &'a [T], split_at }
+
+/// An iterator that will return non-overlapping *mutable* groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give an element to the given function, producing a key, and will give
+/// the key of the first element of the current group and the key of the element
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the key of the element preceding the group
+/// and the key of the last element of the current group.
+pub struct LinearGroupByKeyAnchorMut<'a, T: 'a, F, P> {
+    slice: &'a mut [T],
+    func: F,
+    predicate: P,
+}
+
+impl<'a, T: 'a, F, K, P> LinearGroupByKeyAnchorMut<'a, T, F, P>
+where F: FnMut(&T) -> K,
+      P: FnMut(&K, &K) -> bool,
+{
+    pub fn new(slice: &'a mut [T], func: F, predicate: P) -> Self {
+        LinearGroupByKeyAnchorMut { slice, func, predicate }
+    }
+}
+
+impl<'a, T: 'a, F, P> LinearGroupByKeyAnchorMut<'a, T, F, P> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+group_by_key_anchor!{ struct LinearGroupByKeyAnchorMut, &'a mut [T], split_at_mut }
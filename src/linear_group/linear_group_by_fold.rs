@@ -0,0 +1,142 @@
+use std::{fmt, mem};
+
+macro_rules! group_by_fold {
+    (struct $name:ident, $elem:ty, $split:ident) => {
+        impl<'a, T: 'a, S, F> std::iter::Iterator for $name<'a, T, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, &T) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let (first, tail) = self.slice.split_first()?;
+
+                let step = &mut self.step;
+                let mut state = self.init.clone();
+                step(&mut state, first);
+                let len = 1 + tail.iter().take_while(|&x| step(&mut state, x)).count();
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len);
+                self.slice = tail;
+                Some(head)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+            }
+        }
+
+        impl<'a, T: 'a, S, F> std::iter::DoubleEndedIterator for $name<'a, T, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, &T) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let (last, head) = self.slice.split_last()?;
+
+                let step = &mut self.step;
+                let mut state = self.init.clone();
+                step(&mut state, last);
+                let len = 1 + head.iter().rev().take_while(|&x| step(&mut state, x)).count();
+
+                let slice = mem::take(&mut self.slice);
+                let mid = slice.len() - len;
+                let (head, tail) = slice.$split(mid);
+                self.slice = head;
+                Some(tail)
+            }
+        }
+
+        impl<'a, T: 'a, S, F> std::iter::FusedIterator for $name<'a, T, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, &T) -> bool,
+        { }
+
+        unsafe impl<'a, T: 'a, S, F> crate::GroupIterator for $name<'a, T, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, &T) -> bool,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, S: fmt::Debug, F> fmt::Debug for $name<'a, T, S, F> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("slice", &self.slice)
+                    .field("init", &self.init)
+                    .finish()
+            }
+        }
+    }
+}
+
+/// An iterator that will return non-overlapping groups in the slice
+/// using *linear/sequential search*.
+///
+/// Every group starts with a clone of the initial state, the step function is called
+/// with the state and each element, the first element of a group always joins it and
+/// the following elements join it as long as the step function returns `true`.
+///
+/// When iterating from the back the groups are built starting from their last element,
+/// giving the elements to the step function in reverse order, therefore the groups
+/// are not necessarily the same as the ones returned from the front.
+pub struct LinearGroupByFold<'a, T: 'a, S, F> {
+    slice: &'a [T],
+    init: S,
+    step: F,
+}
+
+impl<'a, T: 'a, S, F> LinearGroupByFold<'a, T, S, F>
+where S: Clone,
+      F: FnMut(&mut S, &T) -> bool,
+{
+    pub fn new(slice: &'a [T], init: S, step: F) -> Self {
+        LinearGroupByFold { slice, init, step }
+    }
+}
+
+impl<'a, T: 'a, S, F> LinearGroupByFold<'a, T, S, F> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+group_by_fold!{ struct LinearGroupByFold, &'a [T], split_at }
+
+/// An iterator that will return non-overlapping *mutable* groups in the slice
+/// using *linear/sequential search*.
+///
+/// Every group starts with a clone of the initial state, the step function is called
+/// with the state and each element, the first element of a group always joins it and
+/// the following elements join it as long as the step function returns `true`.
+///
+/// When iterating from the back the groups are built starting from their last element,
+/// giving the elements to the step function in reverse order, therefore the groups
+/// are not necessarily the same as the ones returned from the front.
+pub struct LinearGroupByFoldMut<'a, T: 'a, S, F> {
+    slice: &'a mut [T],
+    init: S,
+    step: F,
+}
+
+impl<'a, T: 'a, S, F> LinearGroupByFoldMut<'a, T, S, F>
+where S: Clone,
+      F: FnMut(&mut S, &T) -> bool,
+{
+    pub fn new(slice: &'a mut [T], init: S, step: F) -> Self {
+        LinearGroupByFoldMut { slice, init, step }
+    }
+}
+
+impl<'a, T: 'a, S, F> LinearGroupByFoldMut<'a, T, S, F> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+group_by_fold!{ struct LinearGroupByFoldMut, &'a mut [T], split_at_mut }
@@ -0,0 +1,175 @@
+use std::{fmt, mem};
+
+macro_rules! group_marker {
+    (struct $name:ident, $elem:ty, $split:ident, $marker:ident, $front_len:ident, $back_len:ident) => {
+        impl<'a, T: 'a, P> $name<'a, T, P> {
+            #[inline]
+            fn split_front(&mut self, len: usize) -> $elem {
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len);
+                self.slice = tail;
+                head
+            }
+
+            #[inline]
+            fn split_back(&mut self, len: usize) -> $elem {
+                let slice = mem::take(&mut self.slice);
+                let mid = slice.len() - len;
+                let (head, tail) = slice.$split(mid);
+                self.slice = head;
+                tail
+            }
+        }
+
+        impl<'a, T: 'a, P> std::iter::Iterator for $name<'a, T, P>
+        where P: FnMut(&T) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.slice.is_empty() { return None }
+                let len = $front_len(&self.slice, &mut self.$marker);
+                Some(self.split_front(len))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, T: 'a, P> std::iter::DoubleEndedIterator for $name<'a, T, P>
+        where P: FnMut(&T) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.slice.is_empty() { return None }
+                let len = $back_len(&self.slice, &mut self.$marker);
+                Some(self.split_back(len))
+            }
+        }
+
+        impl<'a, T: 'a, P> std::iter::FusedIterator for $name<'a, T, P>
+        where P: FnMut(&T) -> bool,
+        { }
+
+        unsafe impl<'a, T: 'a, P> crate::GroupIterator for $name<'a, T, P>
+        where P: FnMut(&T) -> bool,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, P> fmt::Debug for $name<'a, T, P> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name)).field("slice", &self.slice).finish()
+            }
+        }
+    }
+}
+
+// the first element is always the head of the first group,
+// we look for the next head in the following elements.
+fn starting_front_len<T, P: FnMut(&T) -> bool>(slice: &[T], is_head: &mut P) -> usize {
+    1 + slice[1..].iter().take_while(|&x| !is_head(x)).count()
+}
+
+fn starting_back_len<T, P: FnMut(&T) -> bool>(slice: &[T], is_head: &mut P) -> usize {
+    let start = slice[1..].iter().rposition(is_head).map_or(0, |i| i + 1);
+    slice.len() - start
+}
+
+fn ending_front_len<T, P: FnMut(&T) -> bool>(slice: &[T], is_tail: &mut P) -> usize {
+    let last = slice.len() - 1;
+    slice[..last].iter().position(is_tail).map_or(slice.len(), |i| i + 1)
+}
+
+// the last element is always the tail of the last group,
+// we look for the previous tail in the preceding elements.
+fn ending_back_len<T, P: FnMut(&T) -> bool>(slice: &[T], is_tail: &mut P) -> usize {
+    let last = slice.len() - 1;
+    1 + slice[..last].iter().rev().take_while(|&x| !is_tail(x)).count()
+}
+
+/// An iterator that will return non-overlapping groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give each element to the predicate function, a new group starts
+/// at every element for which it returns `true`.
+pub struct LinearGroupStartingWith<'a, T: 'a, P> {
+    slice: &'a [T],
+    is_head: P,
+}
+
+impl<'a, T: 'a, P> LinearGroupStartingWith<'a, T, P>
+where P: FnMut(&T) -> bool,
+{
+    pub fn new(slice: &'a [T], is_head: P) -> Self {
+        LinearGroupStartingWith { slice, is_head }
+    }
+}
+
+group_marker!{ struct LinearGroupStartingWith, &'a [T], split_at, is_head, starting_front_len, starting_back_len }
+
+/// An iterator that will return non-overlapping *mutable* groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give each element to the predicate function, a new group starts
+/// at every element for which it returns `true`.
+pub struct LinearGroupStartingWithMut<'a, T: 'a, P> {
+    slice: &'a mut [T],
+    is_head: P,
+}
+
+impl<'a, T: 'a, P> LinearGroupStartingWithMut<'a, T, P>
+where P: FnMut(&T) -> bool,
+{
+    pub fn new(slice: &'a mut [T], is_head: P) -> Self {
+        LinearGroupStartingWithMut { slice, is_head }
+    }
+}
+
+group_marker!{ struct LinearGroupStartingWithMut, &'a mut [T], split_at_mut, is_head, starting_front_len, starting_back_len }
+
+/// An iterator that will return non-overlapping groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give each element to the predicate function, a group ends
+/// at every element for which it returns `true`.
+pub struct LinearGroupEndingWith<'a, T: 'a, P> {
+    slice: &'a [T],
+    is_tail: P,
+}
+
+impl<'a, T: 'a, P> LinearGroupEndingWith<'a, T, P>
+where P: FnMut(&T) -> bool,
+{
+    pub fn new(slice: &'a [T], is_tail: P) -> Self {
+        LinearGroupEndingWith { slice, is_tail }
+    }
+}
+
+group_marker!{ struct LinearGroupEndingWith, &'a [T], split_at, is_tail, ending_front_len, ending_back_len }
+
+/// An iterator that will return non-overlapping *mutable* groups in the slice
+/// using *linear/sequential search*.
+///
+/// It will give each element to the predicate function, a group ends
+/// at every element for which it returns `true`.
+pub struct LinearGroupEndingWithMut<'a, T: 'a, P> {
+    slice: &'a mut [T],
+    is_tail: P,
+}
+
+impl<'a, T: 'a, P> LinearGroupEndingWithMut<'a, T, P>
+where P: FnMut(&T) -> bool,
+{
+    pub fn new(slice: &'a mut [T], is_tail: P) -> Self {
+        LinearGroupEndingWithMut { slice, is_tail }
+    }
+}
+
+group_marker!{ struct LinearGroupEndingWithMut, &'a mut [T], split_at_mut, is_tail, ending_front_len, ending_back_len }
@@ -1,10 +1,34 @@
 mod linear_group;
 mod linear_group_by;
 mod linear_group_by_key;
+mod linear_group_by_anchor;
+mod linear_group_by_fold;
+mod linear_group_marker;
+mod gap_group_by_key;
 
 pub use self::linear_group::{LinearGroup, LinearGroupMut};
 pub use self::linear_group_by::{LinearGroupBy, LinearGroupByMut};
 pub use self::linear_group_by_key::{LinearGroupByKey, LinearGroupByKeyMut};
+pub use self::linear_group_by_anchor::{
+    LinearGroupByAnchor,
+    LinearGroupByAnchorMut,
+    LinearGroupByKeyAnchor,
+    LinearGroupByKeyAnchorMut,
+};
+pub use self::linear_group_by_fold::{LinearGroupByFold, LinearGroupByFoldMut};
+pub use self::linear_group_marker::{
+    LinearGroupStartingWith,
+    LinearGroupStartingWithMut,
+    LinearGroupEndingWith,
+    LinearGroupEndingWithMut,
+};
+pub use self::gap_group_by_key::{
+    GapGroupByKey,
+    GapGroupByKeyMut,
+    GapGroupByKeyStats,
+    GapGroupByKeyStatsMut,
+    GapStats,
+};
 
 #[cfg(test)]
 mod tests {
@@ -227,6 +251,224 @@ mod tests {
         assert_eq!(iter.next_back(), Some(&[1, 2, 3, 4, 5][..]));
         assert_eq!(iter.next_back(), None);
     }
+
+    #[test]
+    fn anchor_does_not_drift() {
+        let slice = &[1, 3, 5, 7, 9, 11];
+
+        let mut iter = LinearGroupByAnchor::new(slice, |a, b| b - a <= 5);
+
+        assert_eq!(iter.next(), Some(&[1, 3, 5][..]));
+        assert_eq!(iter.next(), Some(&[7, 9, 11][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn rev_anchor_on_last_element() {
+        let slice = &[1, 3, 5, 7, 9, 11];
+
+        let mut iter = LinearGroupByAnchor::new(slice, |a, b| b - a <= 4).rev();
+
+        assert_eq!(iter.next(), Some(&[7, 9, 11][..]));
+        assert_eq!(iter.next(), Some(&[1, 3, 5][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::double_ended_iterator_last)]
+    fn anchor_last_is_forward_last() {
+        let slice = &[1, 2, 3];
+
+        // from the back the first group is [2, 3]
+        let iter = LinearGroupByAnchor::new(slice, |a, b| b - a <= 1);
+        assert_eq!(iter.last(), Some(&[3][..]));
+
+        let iter = LinearGroupByKeyAnchor::new(slice, |&x| x, |a, b| b - a <= 1);
+        assert_eq!(iter.last(), Some(&[3][..]));
+    }
+
+    #[test]
+    fn key_anchor() {
+        let slice = &[(1, 'a'), (3, 'b'), (5, 'c'), (7, 'd')];
+
+        let mut iter = LinearGroupByKeyAnchor::new(slice, |&(t, _)| t, |a, b| b - a < 4);
+
+        assert_eq!(iter.next(), Some(&[(1, 'a'), (3, 'b')][..]));
+        assert_eq!(iter.next_back(), Some(&[(5, 'c'), (7, 'd')][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn anchor_mut() {
+        let slice = &mut [1, 3, 5, 7, 9, 11];
+
+        for group in LinearGroupByAnchorMut::new(slice, |a, b| b - a <= 5) {
+            let first = group[0];
+            group.iter_mut().for_each(|x| *x = first);
+        }
+
+        assert_eq!(slice, &[1, 1, 1, 7, 7, 7]);
+    }
+
+    #[test]
+    fn fold_bounded_total() {
+        let slice = &[40, 30, 20, 50, 10, 90, 30];
+
+        let mut iter = LinearGroupByFold::new(slice, 0, |total, &x| { *total += x; *total <= 100 });
+
+        assert_eq!(iter.next(), Some(&[40, 30, 20][..]));
+        assert_eq!(iter.next(), Some(&[50, 10][..]));
+        assert_eq!(iter.next(), Some(&[90][..]));
+        assert_eq!(iter.next(), Some(&[30][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::double_ended_iterator_last)]
+    fn fold_last_is_forward_last() {
+        let slice = &[60, 30, 30];
+
+        // from the back the first group is [30, 30]
+        let iter = LinearGroupByFold::new(slice, 0, |total, &x| { *total += x; *total <= 100 });
+        assert_eq!(iter.last(), Some(&[30][..]));
+    }
+
+    #[test]
+    fn rev_fold_bounded_total() {
+        let slice = &[40, 30, 20, 50, 10, 90, 30];
+
+        let mut iter = LinearGroupByFold::new(slice, 0, |total, &x| { *total += x; *total <= 100 }).rev();
+
+        assert_eq!(iter.next(), Some(&[30][..]));
+        assert_eq!(iter.next(), Some(&[10, 90][..]));
+        assert_eq!(iter.next(), Some(&[30, 20, 50][..]));
+        assert_eq!(iter.next(), Some(&[40][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn fold_mut_first_element_always_joins() {
+        let slice = &mut [200, 1, 2, 300];
+
+        let mut iter = LinearGroupByFoldMut::new(slice, 0, |total, &x| { *total += x; *total <= 100 });
+
+        assert_eq!(iter.next(), Some(&mut [200][..]));
+        assert_eq!(iter.next(), Some(&mut [1, 2][..]));
+        assert_eq!(iter.next(), Some(&mut [300][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn starting_with() {
+        let slice = &[1, 2, 0, 3, 0, 0, 4];
+
+        let mut iter = LinearGroupStartingWith::new(slice, |&x| x == 0);
+
+        assert_eq!(iter.next(), Some(&[1, 2][..]));
+        assert_eq!(iter.next(), Some(&[0, 3][..]));
+        assert_eq!(iter.next(), Some(&[0][..]));
+        assert_eq!(iter.next(), Some(&[0, 4][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn rev_starting_with() {
+        let slice = &[0, 1, 2, 0, 3, 0, 0];
+
+        let mut iter = LinearGroupStartingWith::new(slice, |&x| x == 0).rev();
+
+        assert_eq!(iter.next(), Some(&[0][..]));
+        assert_eq!(iter.next(), Some(&[0][..]));
+        assert_eq!(iter.next(), Some(&[0, 3][..]));
+        assert_eq!(iter.next(), Some(&[0, 1, 2][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn ending_with() {
+        let slice = &[1, 0, 0, 2, 3, 0, 4];
+
+        let mut iter = LinearGroupEndingWith::new(slice, |&x| x == 0);
+
+        assert_eq!(iter.next(), Some(&[1, 0][..]));
+        assert_eq!(iter.next(), Some(&[0][..]));
+        assert_eq!(iter.next(), Some(&[2, 3, 0][..]));
+        assert_eq!(iter.next(), Some(&[4][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn double_ended_ending_with_mut() {
+        let slice = &mut [1, 0, 0, 2, 3, 0, 4, 0];
+
+        let mut iter = LinearGroupEndingWithMut::new(slice, |&x| x == 0);
+
+        assert_eq!(iter.next_back(), Some(&mut [4, 0][..]));
+        assert_eq!(iter.next(), Some(&mut [1, 0][..]));
+        assert_eq!(iter.next_back(), Some(&mut [2, 3, 0][..]));
+        assert_eq!(iter.next(), Some(&mut [0][..]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn gap_sessions_rev() {
+        let slice = &[0u32, 2, 4, 10, 12, 30];
+
+        let iter = GapGroupByKey::new(slice, |x| *x, 2);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![&[30][..], &[10, 12], &[0, 2, 4]]);
+
+        let mut iter = GapGroupByKey::new(slice, |x| *x, 6);
+        assert_eq!(iter.next_back(), Some(&[30][..]));
+        assert_eq!(iter.next(), Some(&[0, 2, 4, 10, 12][..]));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn gap_sessions_float_keys() {
+        let slice = &[0.5, 0.75, 2.0, 2.1];
+
+        let iter = GapGroupByKey::new(slice, |x| *x, 0.5);
+        assert_eq!(iter.collect::<Vec<_>>(), vec![&[0.5, 0.75][..], &[2.0, 2.1]]);
+    }
+
+    #[test]
+    fn gap_stats_both_ends() {
+        let slice = &[1, 2, 10, 11, 20, 40];
+
+        let mut iter = GapGroupByKey::new(slice, |x| *x, 3).with_gaps();
+
+        assert_eq!(iter.next(), Some((GapStats { before: None, after: Some(8) }, &[1, 2][..])));
+        assert_eq!(iter.next_back(), Some((GapStats { before: Some(20), after: None }, &[40][..])));
+        assert_eq!(iter.next_back(), Some((GapStats { before: Some(9), after: Some(20) }, &[20][..])));
+        assert_eq!(iter.next(), Some((GapStats { before: Some(8), after: Some(9) }, &[10, 11][..])));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn gap_stats_meet_in_the_middle() {
+        let slice = &[1, 10, 20];
+
+        let mut iter = GapGroupByKey::new(slice, |x| *x, 1).with_gaps();
+
+        assert_eq!(iter.next_back(), Some((GapStats { before: Some(10), after: None }, &[20][..])));
+        assert_eq!(iter.next(), Some((GapStats { before: None, after: Some(9) }, &[1][..])));
+        assert_eq!(iter.next(), Some((GapStats { before: Some(9), after: Some(10) }, &[10][..])));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn gap_sessions_mut() {
+        let slice = &mut [(1, 0), (2, 0), (9, 0), (20, 0), (21, 0)];
+
+        for (stats, group) in GapGroupByKeyMut::new(slice, |x| x.0, 2).with_gaps() {
+            let gap = stats.after.unwrap_or(0);
+            group.iter_mut().for_each(|x| x.1 = gap);
+        }
+
+        assert_eq!(slice, &[(1, 7), (2, 7), (9, 11), (20, 0), (21, 0)]);
+    }
 }
 
 #[cfg(all(feature = "nightly", test))]
@@ -0,0 +1,1295 @@
+// Generated by scripts/char_class_tables.pl from the Unicode 14.0.0 Alphabetic,
+// White_Space and General_Category properties, do not edit.
+
+use super::CharClass;
+
+/// The ranges of `char`s of every class but `Other`.
+pub(crate) static CHAR_CLASS_TABLE: &[(u32, u32, CharClass)] = &[
+    (0x0000, 0x0008, CharClass::Control),
+    (0x0009, 0x000D, CharClass::Whitespace),
+    (0x000E, 0x001F, CharClass::Control),
+    (0x0020, 0x0020, CharClass::Whitespace),
+    (0x0021, 0x0023, CharClass::Punctuation),
+    (0x0024, 0x0024, CharClass::Symbol),
+    (0x0025, 0x002A, CharClass::Punctuation),
+    (0x002B, 0x002B, CharClass::Symbol),
+    (0x002C, 0x002F, CharClass::Punctuation),
+    (0x0030, 0x0039, CharClass::Numeric),
+    (0x003A, 0x003B, CharClass::Punctuation),
+    (0x003C, 0x003E, CharClass::Symbol),
+    (0x003F, 0x0040, CharClass::Punctuation),
+    (0x0041, 0x005A, CharClass::Alphabetic),
+    (0x005B, 0x005D, CharClass::Punctuation),
+    (0x005E, 0x005E, CharClass::Symbol),
+    (0x005F, 0x005F, CharClass::Punctuation),
+    (0x0060, 0x0060, CharClass::Symbol),
+    (0x0061, 0x007A, CharClass::Alphabetic),
+    (0x007B, 0x007B, CharClass::Punctuation),
+    (0x007C, 0x007C, CharClass::Symbol),
+    (0x007D, 0x007D, CharClass::Punctuation),
+    (0x007E, 0x007E, CharClass::Symbol),
+    (0x007F, 0x0084, CharClass::Control),
+    (0x0085, 0x0085, CharClass::Whitespace),
+    (0x0086, 0x009F, CharClass::Control),
+    (0x00A0, 0x00A0, CharClass::Whitespace),
+    (0x00A1, 0x00A1, CharClass::Punctuation),
+    (0x00A2, 0x00A6, CharClass::Symbol),
+    (0x00A7, 0x00A7, CharClass::Punctuation),
+    (0x00A8, 0x00A9, CharClass::Symbol),
+    (0x00AA, 0x00AA, CharClass::Alphabetic),
+    (0x00AB, 0x00AB, CharClass::Punctuation),
+    (0x00AC, 0x00AC, CharClass::Symbol),
+    (0x00AE, 0x00B1, CharClass::Symbol),
+    (0x00B2, 0x00B3, CharClass::Numeric),
+    (0x00B4, 0x00B4, CharClass::Symbol),
+    (0x00B5, 0x00B5, CharClass::Alphabetic),
+    (0x00B6, 0x00B7, CharClass::Punctuation),
+    (0x00B8, 0x00B8, CharClass::Symbol),
+    (0x00B9, 0x00B9, CharClass::Numeric),
+    (0x00BA, 0x00BA, CharClass::Alphabetic),
+    (0x00BB, 0x00BB, CharClass::Punctuation),
+    (0x00BC, 0x00BE, CharClass::Numeric),
+    (0x00BF, 0x00BF, CharClass::Punctuation),
+    (0x00C0, 0x00D6, CharClass::Alphabetic),
+    (0x00D7, 0x00D7, CharClass::Symbol),
+    (0x00D8, 0x00F6, CharClass::Alphabetic),
+    (0x00F7, 0x00F7, CharClass::Symbol),
+    (0x00F8, 0x02C1, CharClass::Alphabetic),
+    (0x02C2, 0x02C5, CharClass::Symbol),
+    (0x02C6, 0x02D1, CharClass::Alphabetic),
+    (0x02D2, 0x02DF, CharClass::Symbol),
+    (0x02E0, 0x02E4, CharClass::Alphabetic),
+    (0x02E5, 0x02EB, CharClass::Symbol),
+    (0x02EC, 0x02EC, CharClass::Alphabetic),
+    (0x02ED, 0x02ED, CharClass::Symbol),
+    (0x02EE, 0x02EE, CharClass::Alphabetic),
+    (0x02EF, 0x02FF, CharClass::Symbol),
+    (0x0345, 0x0345, CharClass::Alphabetic),
+    (0x0370, 0x0374, CharClass::Alphabetic),
+    (0x0375, 0x0375, CharClass::Symbol),
+    (0x0376, 0x0377, CharClass::Alphabetic),
+    (0x037A, 0x037D, CharClass::Alphabetic),
+    (0x037E, 0x037E, CharClass::Punctuation),
+    (0x037F, 0x037F, CharClass::Alphabetic),
+    (0x0384, 0x0385, CharClass::Symbol),
+    (0x0386, 0x0386, CharClass::Alphabetic),
+    (0x0387, 0x0387, CharClass::Punctuation),
+    (0x0388, 0x038A, CharClass::Alphabetic),
+    (0x038C, 0x038C, CharClass::Alphabetic),
+    (0x038E, 0x03A1, CharClass::Alphabetic),
+    (0x03A3, 0x03F5, CharClass::Alphabetic),
+    (0x03F6, 0x03F6, CharClass::Symbol),
+    (0x03F7, 0x0481, CharClass::Alphabetic),
+    (0x0482, 0x0482, CharClass::Symbol),
+    (0x048A, 0x052F, CharClass::Alphabetic),
+    (0x0531, 0x0556, CharClass::Alphabetic),
+    (0x0559, 0x0559, CharClass::Alphabetic),
+    (0x055A, 0x055F, CharClass::Punctuation),
+    (0x0560, 0x0588, CharClass::Alphabetic),
+    (0x0589, 0x058A, CharClass::Punctuation),
+    (0x058D, 0x058F, CharClass::Symbol),
+    (0x05B0, 0x05BD, CharClass::Alphabetic),
+    (0x05BE, 0x05BE, CharClass::Punctuation),
+    (0x05BF, 0x05BF, CharClass::Alphabetic),
+    (0x05C0, 0x05C0, CharClass::Punctuation),
+    (0x05C1, 0x05C2, CharClass::Alphabetic),
+    (0x05C3, 0x05C3, CharClass::Punctuation),
+    (0x05C4, 0x05C5, CharClass::Alphabetic),
+    (0x05C6, 0x05C6, CharClass::Punctuation),
+    (0x05C7, 0x05C7, CharClass::Alphabetic),
+    (0x05D0, 0x05EA, CharClass::Alphabetic),
+    (0x05EF, 0x05F2, CharClass::Alphabetic),
+    (0x05F3, 0x05F4, CharClass::Punctuation),
+    (0x0606, 0x0608, CharClass::Symbol),
+    (0x0609, 0x060A, CharClass::Punctuation),
+    (0x060B, 0x060B, CharClass::Symbol),
+    (0x060C, 0x060D, CharClass::Punctuation),
+    (0x060E, 0x060F, CharClass::Symbol),
+    (0x0610, 0x061A, CharClass::Alphabetic),
+    (0x061B, 0x061B, CharClass::Punctuation),
+    (0x061D, 0x061F, CharClass::Punctuation),
+    (0x0620, 0x0657, CharClass::Alphabetic),
+    (0x0659, 0x065F, CharClass::Alphabetic),
+    (0x0660, 0x0669, CharClass::Numeric),
+    (0x066A, 0x066D, CharClass::Punctuation),
+    (0x066E, 0x06D3, CharClass::Alphabetic),
+    (0x06D4, 0x06D4, CharClass::Punctuation),
+    (0x06D5, 0x06DC, CharClass::Alphabetic),
+    (0x06DE, 0x06DE, CharClass::Symbol),
+    (0x06E1, 0x06E8, CharClass::Alphabetic),
+    (0x06E9, 0x06E9, CharClass::Symbol),
+    (0x06ED, 0x06EF, CharClass::Alphabetic),
+    (0x06F0, 0x06F9, CharClass::Numeric),
+    (0x06FA, 0x06FC, CharClass::Alphabetic),
+    (0x06FD, 0x06FE, CharClass::Symbol),
+    (0x06FF, 0x06FF, CharClass::Alphabetic),
+    (0x0700, 0x070D, CharClass::Punctuation),
+    (0x0710, 0x073F, CharClass::Alphabetic),
+    (0x074D, 0x07B1, CharClass::Alphabetic),
+    (0x07C0, 0x07C9, CharClass::Numeric),
+    (0x07CA, 0x07EA, CharClass::Alphabetic),
+    (0x07F4, 0x07F5, CharClass::Alphabetic),
+    (0x07F6, 0x07F6, CharClass::Symbol),
+    (0x07F7, 0x07F9, CharClass::Punctuation),
+    (0x07FA, 0x07FA, CharClass::Alphabetic),
+    (0x07FE, 0x07FF, CharClass::Symbol),
+    (0x0800, 0x0817, CharClass::Alphabetic),
+    (0x081A, 0x082C, CharClass::Alphabetic),
+    (0x0830, 0x083E, CharClass::Punctuation),
+    (0x0840, 0x0858, CharClass::Alphabetic),
+    (0x085E, 0x085E, CharClass::Punctuation),
+    (0x0860, 0x086A, CharClass::Alphabetic),
+    (0x0870, 0x0887, CharClass::Alphabetic),
+    (0x0888, 0x0888, CharClass::Symbol),
+    (0x0889, 0x088E, CharClass::Alphabetic),
+    (0x08A0, 0x08C9, CharClass::Alphabetic),
+    (0x08D4, 0x08DF, CharClass::Alphabetic),
+    (0x08E3, 0x08E9, CharClass::Alphabetic),
+    (0x08F0, 0x093B, CharClass::Alphabetic),
+    (0x093D, 0x094C, CharClass::Alphabetic),
+    (0x094E, 0x0950, CharClass::Alphabetic),
+    (0x0955, 0x0963, CharClass::Alphabetic),
+    (0x0964, 0x0965, CharClass::Punctuation),
+    (0x0966, 0x096F, CharClass::Numeric),
+    (0x0970, 0x0970, CharClass::Punctuation),
+    (0x0971, 0x0983, CharClass::Alphabetic),
+    (0x0985, 0x098C, CharClass::Alphabetic),
+    (0x098F, 0x0990, CharClass::Alphabetic),
+    (0x0993, 0x09A8, CharClass::Alphabetic),
+    (0x09AA, 0x09B0, CharClass::Alphabetic),
+    (0x09B2, 0x09B2, CharClass::Alphabetic),
+    (0x09B6, 0x09B9, CharClass::Alphabetic),
+    (0x09BD, 0x09C4, CharClass::Alphabetic),
+    (0x09C7, 0x09C8, CharClass::Alphabetic),
+    (0x09CB, 0x09CC, CharClass::Alphabetic),
+    (0x09CE, 0x09CE, CharClass::Alphabetic),
+    (0x09D7, 0x09D7, CharClass::Alphabetic),
+    (0x09DC, 0x09DD, CharClass::Alphabetic),
+    (0x09DF, 0x09E3, CharClass::Alphabetic),
+    (0x09E6, 0x09EF, CharClass::Numeric),
+    (0x09F0, 0x09F1, CharClass::Alphabetic),
+    (0x09F2, 0x09F3, CharClass::Symbol),
+    (0x09F4, 0x09F9, CharClass::Numeric),
+    (0x09FA, 0x09FB, CharClass::Symbol),
+    (0x09FC, 0x09FC, CharClass::Alphabetic),
+    (0x09FD, 0x09FD, CharClass::Punctuation),
+    (0x0A01, 0x0A03, CharClass::Alphabetic),
+    (0x0A05, 0x0A0A, CharClass::Alphabetic),
+    (0x0A0F, 0x0A10, CharClass::Alphabetic),
+    (0x0A13, 0x0A28, CharClass::Alphabetic),
+    (0x0A2A, 0x0A30, CharClass::Alphabetic),
+    (0x0A32, 0x0A33, CharClass::Alphabetic),
+    (0x0A35, 0x0A36, CharClass::Alphabetic),
+    (0x0A38, 0x0A39, CharClass::Alphabetic),
+    (0x0A3E, 0x0A42, CharClass::Alphabetic),
+    (0x0A47, 0x0A48, CharClass::Alphabetic),
+    (0x0A4B, 0x0A4C, CharClass::Alphabetic),
+    (0x0A51, 0x0A51, CharClass::Alphabetic),
+    (0x0A59, 0x0A5C, CharClass::Alphabetic),
+    (0x0A5E, 0x0A5E, CharClass::Alphabetic),
+    (0x0A66, 0x0A6F, CharClass::Numeric),
+    (0x0A70, 0x0A75, CharClass::Alphabetic),
+    (0x0A76, 0x0A76, CharClass::Punctuation),
+    (0x0A81, 0x0A83, CharClass::Alphabetic),
+    (0x0A85, 0x0A8D, CharClass::Alphabetic),
+    (0x0A8F, 0x0A91, CharClass::Alphabetic),
+    (0x0A93, 0x0AA8, CharClass::Alphabetic),
+    (0x0AAA, 0x0AB0, CharClass::Alphabetic),
+    (0x0AB2, 0x0AB3, CharClass::Alphabetic),
+    (0x0AB5, 0x0AB9, CharClass::Alphabetic),
+    (0x0ABD, 0x0AC5, CharClass::Alphabetic),
+    (0x0AC7, 0x0AC9, CharClass::Alphabetic),
+    (0x0ACB, 0x0ACC, CharClass::Alphabetic),
+    (0x0AD0, 0x0AD0, CharClass::Alphabetic),
+    (0x0AE0, 0x0AE3, CharClass::Alphabetic),
+    (0x0AE6, 0x0AEF, CharClass::Numeric),
+    (0x0AF0, 0x0AF0, CharClass::Punctuation),
+    (0x0AF1, 0x0AF1, CharClass::Symbol),
+    (0x0AF9, 0x0AFC, CharClass::Alphabetic),
+    (0x0B01, 0x0B03, CharClass::Alphabetic),
+    (0x0B05, 0x0B0C, CharClass::Alphabetic),
+    (0x0B0F, 0x0B10, CharClass::Alphabetic),
+    (0x0B13, 0x0B28, CharClass::Alphabetic),
+    (0x0B2A, 0x0B30, CharClass::Alphabetic),
+    (0x0B32, 0x0B33, CharClass::Alphabetic),
+    (0x0B35, 0x0B39, CharClass::Alphabetic),
+    (0x0B3D, 0x0B44, CharClass::Alphabetic),
+    (0x0B47, 0x0B48, CharClass::Alphabetic),
+    (0x0B4B, 0x0B4C, CharClass::Alphabetic),
+    (0x0B56, 0x0B57, CharClass::Alphabetic),
+    (0x0B5C, 0x0B5D, CharClass::Alphabetic),
+    (0x0B5F, 0x0B63, CharClass::Alphabetic),
+    (0x0B66, 0x0B6F, CharClass::Numeric),
+    (0x0B70, 0x0B70, CharClass::Symbol),
+    (0x0B71, 0x0B71, CharClass::Alphabetic),
+    (0x0B72, 0x0B77, CharClass::Numeric),
+    (0x0B82, 0x0B83, CharClass::Alphabetic),
+    (0x0B85, 0x0B8A, CharClass::Alphabetic),
+    (0x0B8E, 0x0B90, CharClass::Alphabetic),
+    (0x0B92, 0x0B95, CharClass::Alphabetic),
+    (0x0B99, 0x0B9A, CharClass::Alphabetic),
+    (0x0B9C, 0x0B9C, CharClass::Alphabetic),
+    (0x0B9E, 0x0B9F, CharClass::Alphabetic),
+    (0x0BA3, 0x0BA4, CharClass::Alphabetic),
+    (0x0BA8, 0x0BAA, CharClass::Alphabetic),
+    (0x0BAE, 0x0BB9, CharClass::Alphabetic),
+    (0x0BBE, 0x0BC2, CharClass::Alphabetic),
+    (0x0BC6, 0x0BC8, CharClass::Alphabetic),
+    (0x0BCA, 0x0BCC, CharClass::Alphabetic),
+    (0x0BD0, 0x0BD0, CharClass::Alphabetic),
+    (0x0BD7, 0x0BD7, CharClass::Alphabetic),
+    (0x0BE6, 0x0BF2, CharClass::Numeric),
+    (0x0BF3, 0x0BFA, CharClass::Symbol),
+    (0x0C00, 0x0C03, CharClass::Alphabetic),
+    (0x0C05, 0x0C0C, CharClass::Alphabetic),
+    (0x0C0E, 0x0C10, CharClass::Alphabetic),
+    (0x0C12, 0x0C28, CharClass::Alphabetic),
+    (0x0C2A, 0x0C39, CharClass::Alphabetic),
+    (0x0C3D, 0x0C44, CharClass::Alphabetic),
+    (0x0C46, 0x0C48, CharClass::Alphabetic),
+    (0x0C4A, 0x0C4C, CharClass::Alphabetic),
+    (0x0C55, 0x0C56, CharClass::Alphabetic),
+    (0x0C58, 0x0C5A, CharClass::Alphabetic),
+    (0x0C5D, 0x0C5D, CharClass::Alphabetic),
+    (0x0C60, 0x0C63, CharClass::Alphabetic),
+    (0x0C66, 0x0C6F, CharClass::Numeric),
+    (0x0C77, 0x0C77, CharClass::Punctuation),
+    (0x0C78, 0x0C7E, CharClass::Numeric),
+    (0x0C7F, 0x0C7F, CharClass::Symbol),
+    (0x0C80, 0x0C83, CharClass::Alphabetic),
+    (0x0C84, 0x0C84, CharClass::Punctuation),
+    (0x0C85, 0x0C8C, CharClass::Alphabetic),
+    (0x0C8E, 0x0C90, CharClass::Alphabetic),
+    (0x0C92, 0x0CA8, CharClass::Alphabetic),
+    (0x0CAA, 0x0CB3, CharClass::Alphabetic),
+    (0x0CB5, 0x0CB9, CharClass::Alphabetic),
+    (0x0CBD, 0x0CC4, CharClass::Alphabetic),
+    (0x0CC6, 0x0CC8, CharClass::Alphabetic),
+    (0x0CCA, 0x0CCC, CharClass::Alphabetic),
+    (0x0CD5, 0x0CD6, CharClass::Alphabetic),
+    (0x0CDD, 0x0CDE, CharClass::Alphabetic),
+    (0x0CE0, 0x0CE3, CharClass::Alphabetic),
+    (0x0CE6, 0x0CEF, CharClass::Numeric),
+    (0x0CF1, 0x0CF2, CharClass::Alphabetic),
+    (0x0D00, 0x0D0C, CharClass::Alphabetic),
+    (0x0D0E, 0x0D10, CharClass::Alphabetic),
+    (0x0D12, 0x0D3A, CharClass::Alphabetic),
+    (0x0D3D, 0x0D44, CharClass::Alphabetic),
+    (0x0D46, 0x0D48, CharClass::Alphabetic),
+    (0x0D4A, 0x0D4C, CharClass::Alphabetic),
+    (0x0D4E, 0x0D4E, CharClass::Alphabetic),
+    (0x0D4F, 0x0D4F, CharClass::Symbol),
+    (0x0D54, 0x0D57, CharClass::Alphabetic),
+    (0x0D58, 0x0D5E, CharClass::Numeric),
+    (0x0D5F, 0x0D63, CharClass::Alphabetic),
+    (0x0D66, 0x0D78, CharClass::Numeric),
+    (0x0D79, 0x0D79, CharClass::Symbol),
+    (0x0D7A, 0x0D7F, CharClass::Alphabetic),
+    (0x0D81, 0x0D83, CharClass::Alphabetic),
+    (0x0D85, 0x0D96, CharClass::Alphabetic),
+    (0x0D9A, 0x0DB1, CharClass::Alphabetic),
+    (0x0DB3, 0x0DBB, CharClass::Alphabetic),
+    (0x0DBD, 0x0DBD, CharClass::Alphabetic),
+    (0x0DC0, 0x0DC6, CharClass::Alphabetic),
+    (0x0DCF, 0x0DD4, CharClass::Alphabetic),
+    (0x0DD6, 0x0DD6, CharClass::Alphabetic),
+    (0x0DD8, 0x0DDF, CharClass::Alphabetic),
+    (0x0DE6, 0x0DEF, CharClass::Numeric),
+    (0x0DF2, 0x0DF3, CharClass::Alphabetic),
+    (0x0DF4, 0x0DF4, CharClass::Punctuation),
+    (0x0E01, 0x0E3A, CharClass::Alphabetic),
+    (0x0E3F, 0x0E3F, CharClass::Symbol),
+    (0x0E40, 0x0E46, CharClass::Alphabetic),
+    (0x0E4D, 0x0E4D, CharClass::Alphabetic),
+    (0x0E4F, 0x0E4F, CharClass::Punctuation),
+    (0x0E50, 0x0E59, CharClass::Numeric),
+    (0x0E5A, 0x0E5B, CharClass::Punctuation),
+    (0x0E81, 0x0E82, CharClass::Alphabetic),
+    (0x0E84, 0x0E84, CharClass::Alphabetic),
+    (0x0E86, 0x0E8A, CharClass::Alphabetic),
+    (0x0E8C, 0x0EA3, CharClass::Alphabetic),
+    (0x0EA5, 0x0EA5, CharClass::Alphabetic),
+    (0x0EA7, 0x0EB9, CharClass::Alphabetic),
+    (0x0EBB, 0x0EBD, CharClass::Alphabetic),
+    (0x0EC0, 0x0EC4, CharClass::Alphabetic),
+    (0x0EC6, 0x0EC6, CharClass::Alphabetic),
+    (0x0ECD, 0x0ECD, CharClass::Alphabetic),
+    (0x0ED0, 0x0ED9, CharClass::Numeric),
+    (0x0EDC, 0x0EDF, CharClass::Alphabetic),
+    (0x0F00, 0x0F00, CharClass::Alphabetic),
+    (0x0F01, 0x0F03, CharClass::Symbol),
+    (0x0F04, 0x0F12, CharClass::Punctuation),
+    (0x0F13, 0x0F13, CharClass::Symbol),
+    (0x0F14, 0x0F14, CharClass::Punctuation),
+    (0x0F15, 0x0F17, CharClass::Symbol),
+    (0x0F1A, 0x0F1F, CharClass::Symbol),
+    (0x0F20, 0x0F33, CharClass::Numeric),
+    (0x0F34, 0x0F34, CharClass::Symbol),
+    (0x0F36, 0x0F36, CharClass::Symbol),
+    (0x0F38, 0x0F38, CharClass::Symbol),
+    (0x0F3A, 0x0F3D, CharClass::Punctuation),
+    (0x0F40, 0x0F47, CharClass::Alphabetic),
+    (0x0F49, 0x0F6C, CharClass::Alphabetic),
+    (0x0F71, 0x0F81, CharClass::Alphabetic),
+    (0x0F85, 0x0F85, CharClass::Punctuation),
+    (0x0F88, 0x0F97, CharClass::Alphabetic),
+    (0x0F99, 0x0FBC, CharClass::Alphabetic),
+    (0x0FBE, 0x0FC5, CharClass::Symbol),
+    (0x0FC7, 0x0FCC, CharClass::Symbol),
+    (0x0FCE, 0x0FCF, CharClass::Symbol),
+    (0x0FD0, 0x0FD4, CharClass::Punctuation),
+    (0x0FD5, 0x0FD8, CharClass::Symbol),
+    (0x0FD9, 0x0FDA, CharClass::Punctuation),
+    (0x1000, 0x1036, CharClass::Alphabetic),
+    (0x1038, 0x1038, CharClass::Alphabetic),
+    (0x103B, 0x103F, CharClass::Alphabetic),
+    (0x1040, 0x1049, CharClass::Numeric),
+    (0x104A, 0x104F, CharClass::Punctuation),
+    (0x1050, 0x108F, CharClass::Alphabetic),
+    (0x1090, 0x1099, CharClass::Numeric),
+    (0x109A, 0x109D, CharClass::Alphabetic),
+    (0x109E, 0x109F, CharClass::Symbol),
+    (0x10A0, 0x10C5, CharClass::Alphabetic),
+    (0x10C7, 0x10C7, CharClass::Alphabetic),
+    (0x10CD, 0x10CD, CharClass::Alphabetic),
+    (0x10D0, 0x10FA, CharClass::Alphabetic),
+    (0x10FB, 0x10FB, CharClass::Punctuation),
+    (0x10FC, 0x1248, CharClass::Alphabetic),
+    (0x124A, 0x124D, CharClass::Alphabetic),
+    (0x1250, 0x1256, CharClass::Alphabetic),
+    (0x1258, 0x1258, CharClass::Alphabetic),
+    (0x125A, 0x125D, CharClass::Alphabetic),
+    (0x1260, 0x1288, CharClass::Alphabetic),
+    (0x128A, 0x128D, CharClass::Alphabetic),
+    (0x1290, 0x12B0, CharClass::Alphabetic),
+    (0x12B2, 0x12B5, CharClass::Alphabetic),
+    (0x12B8, 0x12BE, CharClass::Alphabetic),
+    (0x12C0, 0x12C0, CharClass::Alphabetic),
+    (0x12C2, 0x12C5, CharClass::Alphabetic),
+    (0x12C8, 0x12D6, CharClass::Alphabetic),
+    (0x12D8, 0x1310, CharClass::Alphabetic),
+    (0x1312, 0x1315, CharClass::Alphabetic),
+    (0x1318, 0x135A, CharClass::Alphabetic),
+    (0x1360, 0x1368, CharClass::Punctuation),
+    (0x1369, 0x137C, CharClass::Numeric),
+    (0x1380, 0x138F, CharClass::Alphabetic),
+    (0x1390, 0x1399, CharClass::Symbol),
+    (0x13A0, 0x13F5, CharClass::Alphabetic),
+    (0x13F8, 0x13FD, CharClass::Alphabetic),
+    (0x1400, 0x1400, CharClass::Punctuation),
+    (0x1401, 0x166C, CharClass::Alphabetic),
+    (0x166D, 0x166D, CharClass::Symbol),
+    (0x166E, 0x166E, CharClass::Punctuation),
+    (0x166F, 0x167F, CharClass::Alphabetic),
+    (0x1680, 0x1680, CharClass::Whitespace),
+    (0x1681, 0x169A, CharClass::Alphabetic),
+    (0x169B, 0x169C, CharClass::Punctuation),
+    (0x16A0, 0x16EA, CharClass::Alphabetic),
+    (0x16EB, 0x16ED, CharClass::Punctuation),
+    (0x16EE, 0x16F8, CharClass::Alphabetic),
+    (0x1700, 0x1713, CharClass::Alphabetic),
+    (0x171F, 0x1733, CharClass::Alphabetic),
+    (0x1735, 0x1736, CharClass::Punctuation),
+    (0x1740, 0x1753, CharClass::Alphabetic),
+    (0x1760, 0x176C, CharClass::Alphabetic),
+    (0x176E, 0x1770, CharClass::Alphabetic),
+    (0x1772, 0x1773, CharClass::Alphabetic),
+    (0x1780, 0x17B3, CharClass::Alphabetic),
+    (0x17B6, 0x17C8, CharClass::Alphabetic),
+    (0x17D4, 0x17D6, CharClass::Punctuation),
+    (0x17D7, 0x17D7, CharClass::Alphabetic),
+    (0x17D8, 0x17DA, CharClass::Punctuation),
+    (0x17DB, 0x17DB, CharClass::Symbol),
+    (0x17DC, 0x17DC, CharClass::Alphabetic),
+    (0x17E0, 0x17E9, CharClass::Numeric),
+    (0x17F0, 0x17F9, CharClass::Numeric),
+    (0x1800, 0x180A, CharClass::Punctuation),
+    (0x1810, 0x1819, CharClass::Numeric),
+    (0x1820, 0x1878, CharClass::Alphabetic),
+    (0x1880, 0x18AA, CharClass::Alphabetic),
+    (0x18B0, 0x18F5, CharClass::Alphabetic),
+    (0x1900, 0x191E, CharClass::Alphabetic),
+    (0x1920, 0x192B, CharClass::Alphabetic),
+    (0x1930, 0x1938, CharClass::Alphabetic),
+    (0x1940, 0x1940, CharClass::Symbol),
+    (0x1944, 0x1945, CharClass::Punctuation),
+    (0x1946, 0x194F, CharClass::Numeric),
+    (0x1950, 0x196D, CharClass::Alphabetic),
+    (0x1970, 0x1974, CharClass::Alphabetic),
+    (0x1980, 0x19AB, CharClass::Alphabetic),
+    (0x19B0, 0x19C9, CharClass::Alphabetic),
+    (0x19D0, 0x19DA, CharClass::Numeric),
+    (0x19DE, 0x19FF, CharClass::Symbol),
+    (0x1A00, 0x1A1B, CharClass::Alphabetic),
+    (0x1A1E, 0x1A1F, CharClass::Punctuation),
+    (0x1A20, 0x1A5E, CharClass::Alphabetic),
+    (0x1A61, 0x1A74, CharClass::Alphabetic),
+    (0x1A80, 0x1A89, CharClass::Numeric),
+    (0x1A90, 0x1A99, CharClass::Numeric),
+    (0x1AA0, 0x1AA6, CharClass::Punctuation),
+    (0x1AA7, 0x1AA7, CharClass::Alphabetic),
+    (0x1AA8, 0x1AAD, CharClass::Punctuation),
+    (0x1ABF, 0x1AC0, CharClass::Alphabetic),
+    (0x1ACC, 0x1ACE, CharClass::Alphabetic),
+    (0x1B00, 0x1B33, CharClass::Alphabetic),
+    (0x1B35, 0x1B43, CharClass::Alphabetic),
+    (0x1B45, 0x1B4C, CharClass::Alphabetic),
+    (0x1B50, 0x1B59, CharClass::Numeric),
+    (0x1B5A, 0x1B60, CharClass::Punctuation),
+    (0x1B61, 0x1B6A, CharClass::Symbol),
+    (0x1B74, 0x1B7C, CharClass::Symbol),
+    (0x1B7D, 0x1B7E, CharClass::Punctuation),
+    (0x1B80, 0x1BA9, CharClass::Alphabetic),
+    (0x1BAC, 0x1BAF, CharClass::Alphabetic),
+    (0x1BB0, 0x1BB9, CharClass::Numeric),
+    (0x1BBA, 0x1BE5, CharClass::Alphabetic),
+    (0x1BE7, 0x1BF1, CharClass::Alphabetic),
+    (0x1BFC, 0x1BFF, CharClass::Punctuation),
+    (0x1C00, 0x1C36, CharClass::Alphabetic),
+    (0x1C3B, 0x1C3F, CharClass::Punctuation),
+    (0x1C40, 0x1C49, CharClass::Numeric),
+    (0x1C4D, 0x1C4F, CharClass::Alphabetic),
+    (0x1C50, 0x1C59, CharClass::Numeric),
+    (0x1C5A, 0x1C7D, CharClass::Alphabetic),
+    (0x1C7E, 0x1C7F, CharClass::Punctuation),
+    (0x1C80, 0x1C88, CharClass::Alphabetic),
+    (0x1C90, 0x1CBA, CharClass::Alphabetic),
+    (0x1CBD, 0x1CBF, CharClass::Alphabetic),
+    (0x1CC0, 0x1CC7, CharClass::Punctuation),
+    (0x1CD3, 0x1CD3, CharClass::Punctuation),
+    (0x1CE9, 0x1CEC, CharClass::Alphabetic),
+    (0x1CEE, 0x1CF3, CharClass::Alphabetic),
+    (0x1CF5, 0x1CF6, CharClass::Alphabetic),
+    (0x1CFA, 0x1CFA, CharClass::Alphabetic),
+    (0x1D00, 0x1DBF, CharClass::Alphabetic),
+    (0x1DE7, 0x1DF4, CharClass::Alphabetic),
+    (0x1E00, 0x1F15, CharClass::Alphabetic),
+    (0x1F18, 0x1F1D, CharClass::Alphabetic),
+    (0x1F20, 0x1F45, CharClass::Alphabetic),
+    (0x1F48, 0x1F4D, CharClass::Alphabetic),
+    (0x1F50, 0x1F57, CharClass::Alphabetic),
+    (0x1F59, 0x1F59, CharClass::Alphabetic),
+    (0x1F5B, 0x1F5B, CharClass::Alphabetic),
+    (0x1F5D, 0x1F5D, CharClass::Alphabetic),
+    (0x1F5F, 0x1F7D, CharClass::Alphabetic),
+    (0x1F80, 0x1FB4, CharClass::Alphabetic),
+    (0x1FB6, 0x1FBC, CharClass::Alphabetic),
+    (0x1FBD, 0x1FBD, CharClass::Symbol),
+    (0x1FBE, 0x1FBE, CharClass::Alphabetic),
+    (0x1FBF, 0x1FC1, CharClass::Symbol),
+    (0x1FC2, 0x1FC4, CharClass::Alphabetic),
+    (0x1FC6, 0x1FCC, CharClass::Alphabetic),
+    (0x1FCD, 0x1FCF, CharClass::Symbol),
+    (0x1FD0, 0x1FD3, CharClass::Alphabetic),
+    (0x1FD6, 0x1FDB, CharClass::Alphabetic),
+    (0x1FDD, 0x1FDF, CharClass::Symbol),
+    (0x1FE0, 0x1FEC, CharClass::Alphabetic),
+    (0x1FED, 0x1FEF, CharClass::Symbol),
+    (0x1FF2, 0x1FF4, CharClass::Alphabetic),
+    (0x1FF6, 0x1FFC, CharClass::Alphabetic),
+    (0x1FFD, 0x1FFE, CharClass::Symbol),
+    (0x2000, 0x200A, CharClass::Whitespace),
+    (0x2010, 0x2027, CharClass::Punctuation),
+    (0x2028, 0x2029, CharClass::Whitespace),
+    (0x202F, 0x202F, CharClass::Whitespace),
+    (0x2030, 0x2043, CharClass::Punctuation),
+    (0x2044, 0x2044, CharClass::Symbol),
+    (0x2045, 0x2051, CharClass::Punctuation),
+    (0x2052, 0x2052, CharClass::Symbol),
+    (0x2053, 0x205E, CharClass::Punctuation),
+    (0x205F, 0x205F, CharClass::Whitespace),
+    (0x2070, 0x2070, CharClass::Numeric),
+    (0x2071, 0x2071, CharClass::Alphabetic),
+    (0x2074, 0x2079, CharClass::Numeric),
+    (0x207A, 0x207C, CharClass::Symbol),
+    (0x207D, 0x207E, CharClass::Punctuation),
+    (0x207F, 0x207F, CharClass::Alphabetic),
+    (0x2080, 0x2089, CharClass::Numeric),
+    (0x208A, 0x208C, CharClass::Symbol),
+    (0x208D, 0x208E, CharClass::Punctuation),
+    (0x2090, 0x209C, CharClass::Alphabetic),
+    (0x20A0, 0x20C0, CharClass::Symbol),
+    (0x2100, 0x2101, CharClass::Symbol),
+    (0x2102, 0x2102, CharClass::Alphabetic),
+    (0x2103, 0x2106, CharClass::Symbol),
+    (0x2107, 0x2107, CharClass::Alphabetic),
+    (0x2108, 0x2109, CharClass::Symbol),
+    (0x210A, 0x2113, CharClass::Alphabetic),
+    (0x2114, 0x2114, CharClass::Symbol),
+    (0x2115, 0x2115, CharClass::Alphabetic),
+    (0x2116, 0x2118, CharClass::Symbol),
+    (0x2119, 0x211D, CharClass::Alphabetic),
+    (0x211E, 0x2123, CharClass::Symbol),
+    (0x2124, 0x2124, CharClass::Alphabetic),
+    (0x2125, 0x2125, CharClass::Symbol),
+    (0x2126, 0x2126, CharClass::Alphabetic),
+    (0x2127, 0x2127, CharClass::Symbol),
+    (0x2128, 0x2128, CharClass::Alphabetic),
+    (0x2129, 0x2129, CharClass::Symbol),
+    (0x212A, 0x212D, CharClass::Alphabetic),
+    (0x212E, 0x212E, CharClass::Symbol),
+    (0x212F, 0x2139, CharClass::Alphabetic),
+    (0x213A, 0x213B, CharClass::Symbol),
+    (0x213C, 0x213F, CharClass::Alphabetic),
+    (0x2140, 0x2144, CharClass::Symbol),
+    (0x2145, 0x2149, CharClass::Alphabetic),
+    (0x214A, 0x214D, CharClass::Symbol),
+    (0x214E, 0x214E, CharClass::Alphabetic),
+    (0x214F, 0x214F, CharClass::Symbol),
+    (0x2150, 0x215F, CharClass::Numeric),
+    (0x2160, 0x2188, CharClass::Alphabetic),
+    (0x2189, 0x2189, CharClass::Numeric),
+    (0x218A, 0x218B, CharClass::Symbol),
+    (0x2190, 0x2307, CharClass::Symbol),
+    (0x2308, 0x230B, CharClass::Punctuation),
+    (0x230C, 0x2328, CharClass::Symbol),
+    (0x2329, 0x232A, CharClass::Punctuation),
+    (0x232B, 0x2426, CharClass::Symbol),
+    (0x2440, 0x244A, CharClass::Symbol),
+    (0x2460, 0x249B, CharClass::Numeric),
+    (0x249C, 0x24B5, CharClass::Symbol),
+    (0x24B6, 0x24E9, CharClass::Alphabetic),
+    (0x24EA, 0x24FF, CharClass::Numeric),
+    (0x2500, 0x2767, CharClass::Symbol),
+    (0x2768, 0x2775, CharClass::Punctuation),
+    (0x2776, 0x2793, CharClass::Numeric),
+    (0x2794, 0x27C4, CharClass::Symbol),
+    (0x27C5, 0x27C6, CharClass::Punctuation),
+    (0x27C7, 0x27E5, CharClass::Symbol),
+    (0x27E6, 0x27EF, CharClass::Punctuation),
+    (0x27F0, 0x2982, CharClass::Symbol),
+    (0x2983, 0x2998, CharClass::Punctuation),
+    (0x2999, 0x29D7, CharClass::Symbol),
+    (0x29D8, 0x29DB, CharClass::Punctuation),
+    (0x29DC, 0x29FB, CharClass::Symbol),
+    (0x29FC, 0x29FD, CharClass::Punctuation),
+    (0x29FE, 0x2B73, CharClass::Symbol),
+    (0x2B76, 0x2B95, CharClass::Symbol),
+    (0x2B97, 0x2BFF, CharClass::Symbol),
+    (0x2C00, 0x2CE4, CharClass::Alphabetic),
+    (0x2CE5, 0x2CEA, CharClass::Symbol),
+    (0x2CEB, 0x2CEE, CharClass::Alphabetic),
+    (0x2CF2, 0x2CF3, CharClass::Alphabetic),
+    (0x2CF9, 0x2CFC, CharClass::Punctuation),
+    (0x2CFD, 0x2CFD, CharClass::Numeric),
+    (0x2CFE, 0x2CFF, CharClass::Punctuation),
+    (0x2D00, 0x2D25, CharClass::Alphabetic),
+    (0x2D27, 0x2D27, CharClass::Alphabetic),
+    (0x2D2D, 0x2D2D, CharClass::Alphabetic),
+    (0x2D30, 0x2D67, CharClass::Alphabetic),
+    (0x2D6F, 0x2D6F, CharClass::Alphabetic),
+    (0x2D70, 0x2D70, CharClass::Punctuation),
+    (0x2D80, 0x2D96, CharClass::Alphabetic),
+    (0x2DA0, 0x2DA6, CharClass::Alphabetic),
+    (0x2DA8, 0x2DAE, CharClass::Alphabetic),
+    (0x2DB0, 0x2DB6, CharClass::Alphabetic),
+    (0x2DB8, 0x2DBE, CharClass::Alphabetic),
+    (0x2DC0, 0x2DC6, CharClass::Alphabetic),
+    (0x2DC8, 0x2DCE, CharClass::Alphabetic),
+    (0x2DD0, 0x2DD6, CharClass::Alphabetic),
+    (0x2DD8, 0x2DDE, CharClass::Alphabetic),
+    (0x2DE0, 0x2DFF, CharClass::Alphabetic),
+    (0x2E00, 0x2E2E, CharClass::Punctuation),
+    (0x2E2F, 0x2E2F, CharClass::Alphabetic),
+    (0x2E30, 0x2E4F, CharClass::Punctuation),
+    (0x2E50, 0x2E51, CharClass::Symbol),
+    (0x2E52, 0x2E5D, CharClass::Punctuation),
+    (0x2E80, 0x2E99, CharClass::Symbol),
+    (0x2E9B, 0x2EF3, CharClass::Symbol),
+    (0x2F00, 0x2FD5, CharClass::Symbol),
+    (0x2FF0, 0x2FFB, CharClass::Symbol),
+    (0x3000, 0x3000, CharClass::Whitespace),
+    (0x3001, 0x3003, CharClass::Punctuation),
+    (0x3004, 0x3004, CharClass::Symbol),
+    (0x3005, 0x3007, CharClass::Alphabetic),
+    (0x3008, 0x3011, CharClass::Punctuation),
+    (0x3012, 0x3013, CharClass::Symbol),
+    (0x3014, 0x301F, CharClass::Punctuation),
+    (0x3020, 0x3020, CharClass::Symbol),
+    (0x3021, 0x3029, CharClass::Alphabetic),
+    (0x3030, 0x3030, CharClass::Punctuation),
+    (0x3031, 0x3035, CharClass::Alphabetic),
+    (0x3036, 0x3037, CharClass::Symbol),
+    (0x3038, 0x303C, CharClass::Alphabetic),
+    (0x303D, 0x303D, CharClass::Punctuation),
+    (0x303E, 0x303F, CharClass::Symbol),
+    (0x3041, 0x3096, CharClass::Alphabetic),
+    (0x309B, 0x309C, CharClass::Symbol),
+    (0x309D, 0x309F, CharClass::Alphabetic),
+    (0x30A0, 0x30A0, CharClass::Punctuation),
+    (0x30A1, 0x30FA, CharClass::Alphabetic),
+    (0x30FB, 0x30FB, CharClass::Punctuation),
+    (0x30FC, 0x30FF, CharClass::Alphabetic),
+    (0x3105, 0x312F, CharClass::Alphabetic),
+    (0x3131, 0x318E, CharClass::Alphabetic),
+    (0x3190, 0x3191, CharClass::Symbol),
+    (0x3192, 0x3195, CharClass::Numeric),
+    (0x3196, 0x319F, CharClass::Symbol),
+    (0x31A0, 0x31BF, CharClass::Alphabetic),
+    (0x31C0, 0x31E3, CharClass::Symbol),
+    (0x31F0, 0x31FF, CharClass::Alphabetic),
+    (0x3200, 0x321E, CharClass::Symbol),
+    (0x3220, 0x3229, CharClass::Numeric),
+    (0x322A, 0x3247, CharClass::Symbol),
+    (0x3248, 0x324F, CharClass::Numeric),
+    (0x3250, 0x3250, CharClass::Symbol),
+    (0x3251, 0x325F, CharClass::Numeric),
+    (0x3260, 0x327F, CharClass::Symbol),
+    (0x3280, 0x3289, CharClass::Numeric),
+    (0x328A, 0x32B0, CharClass::Symbol),
+    (0x32B1, 0x32BF, CharClass::Numeric),
+    (0x32C0, 0x33FF, CharClass::Symbol),
+    (0x3400, 0x4DBF, CharClass::Alphabetic),
+    (0x4DC0, 0x4DFF, CharClass::Symbol),
+    (0x4E00, 0xA48C, CharClass::Alphabetic),
+    (0xA490, 0xA4C6, CharClass::Symbol),
+    (0xA4D0, 0xA4FD, CharClass::Alphabetic),
+    (0xA4FE, 0xA4FF, CharClass::Punctuation),
+    (0xA500, 0xA60C, CharClass::Alphabetic),
+    (0xA60D, 0xA60F, CharClass::Punctuation),
+    (0xA610, 0xA61F, CharClass::Alphabetic),
+    (0xA620, 0xA629, CharClass::Numeric),
+    (0xA62A, 0xA62B, CharClass::Alphabetic),
+    (0xA640, 0xA66E, CharClass::Alphabetic),
+    (0xA673, 0xA673, CharClass::Punctuation),
+    (0xA674, 0xA67B, CharClass::Alphabetic),
+    (0xA67E, 0xA67E, CharClass::Punctuation),
+    (0xA67F, 0xA6EF, CharClass::Alphabetic),
+    (0xA6F2, 0xA6F7, CharClass::Punctuation),
+    (0xA700, 0xA716, CharClass::Symbol),
+    (0xA717, 0xA71F, CharClass::Alphabetic),
+    (0xA720, 0xA721, CharClass::Symbol),
+    (0xA722, 0xA788, CharClass::Alphabetic),
+    (0xA789, 0xA78A, CharClass::Symbol),
+    (0xA78B, 0xA7CA, CharClass::Alphabetic),
+    (0xA7D0, 0xA7D1, CharClass::Alphabetic),
+    (0xA7D3, 0xA7D3, CharClass::Alphabetic),
+    (0xA7D5, 0xA7D9, CharClass::Alphabetic),
+    (0xA7F2, 0xA805, CharClass::Alphabetic),
+    (0xA807, 0xA827, CharClass::Alphabetic),
+    (0xA828, 0xA82B, CharClass::Symbol),
+    (0xA830, 0xA835, CharClass::Numeric),
+    (0xA836, 0xA839, CharClass::Symbol),
+    (0xA840, 0xA873, CharClass::Alphabetic),
+    (0xA874, 0xA877, CharClass::Punctuation),
+    (0xA880, 0xA8C3, CharClass::Alphabetic),
+    (0xA8C5, 0xA8C5, CharClass::Alphabetic),
+    (0xA8CE, 0xA8CF, CharClass::Punctuation),
+    (0xA8D0, 0xA8D9, CharClass::Numeric),
+    (0xA8F2, 0xA8F7, CharClass::Alphabetic),
+    (0xA8F8, 0xA8FA, CharClass::Punctuation),
+    (0xA8FB, 0xA8FB, CharClass::Alphabetic),
+    (0xA8FC, 0xA8FC, CharClass::Punctuation),
+    (0xA8FD, 0xA8FF, CharClass::Alphabetic),
+    (0xA900, 0xA909, CharClass::Numeric),
+    (0xA90A, 0xA92A, CharClass::Alphabetic),
+    (0xA92E, 0xA92F, CharClass::Punctuation),
+    (0xA930, 0xA952, CharClass::Alphabetic),
+    (0xA95F, 0xA95F, CharClass::Punctuation),
+    (0xA960, 0xA97C, CharClass::Alphabetic),
+    (0xA980, 0xA9B2, CharClass::Alphabetic),
+    (0xA9B4, 0xA9BF, CharClass::Alphabetic),
+    (0xA9C1, 0xA9CD, CharClass::Punctuation),
+    (0xA9CF, 0xA9CF, CharClass::Alphabetic),
+    (0xA9D0, 0xA9D9, CharClass::Numeric),
+    (0xA9DE, 0xA9DF, CharClass::Punctuation),
+    (0xA9E0, 0xA9EF, CharClass::Alphabetic),
+    (0xA9F0, 0xA9F9, CharClass::Numeric),
+    (0xA9FA, 0xA9FE, CharClass::Alphabetic),
+    (0xAA00, 0xAA36, CharClass::Alphabetic),
+    (0xAA40, 0xAA4D, CharClass::Alphabetic),
+    (0xAA50, 0xAA59, CharClass::Numeric),
+    (0xAA5C, 0xAA5F, CharClass::Punctuation),
+    (0xAA60, 0xAA76, CharClass::Alphabetic),
+    (0xAA77, 0xAA79, CharClass::Symbol),
+    (0xAA7A, 0xAABE, CharClass::Alphabetic),
+    (0xAAC0, 0xAAC0, CharClass::Alphabetic),
+    (0xAAC2, 0xAAC2, CharClass::Alphabetic),
+    (0xAADB, 0xAADD, CharClass::Alphabetic),
+    (0xAADE, 0xAADF, CharClass::Punctuation),
+    (0xAAE0, 0xAAEF, CharClass::Alphabetic),
+    (0xAAF0, 0xAAF1, CharClass::Punctuation),
+    (0xAAF2, 0xAAF5, CharClass::Alphabetic),
+    (0xAB01, 0xAB06, CharClass::Alphabetic),
+    (0xAB09, 0xAB0E, CharClass::Alphabetic),
+    (0xAB11, 0xAB16, CharClass::Alphabetic),
+    (0xAB20, 0xAB26, CharClass::Alphabetic),
+    (0xAB28, 0xAB2E, CharClass::Alphabetic),
+    (0xAB30, 0xAB5A, CharClass::Alphabetic),
+    (0xAB5B, 0xAB5B, CharClass::Symbol),
+    (0xAB5C, 0xAB69, CharClass::Alphabetic),
+    (0xAB6A, 0xAB6B, CharClass::Symbol),
+    (0xAB70, 0xABEA, CharClass::Alphabetic),
+    (0xABEB, 0xABEB, CharClass::Punctuation),
+    (0xABF0, 0xABF9, CharClass::Numeric),
+    (0xAC00, 0xD7A3, CharClass::Alphabetic),
+    (0xD7B0, 0xD7C6, CharClass::Alphabetic),
+    (0xD7CB, 0xD7FB, CharClass::Alphabetic),
+    (0xF900, 0xFA6D, CharClass::Alphabetic),
+    (0xFA70, 0xFAD9, CharClass::Alphabetic),
+    (0xFB00, 0xFB06, CharClass::Alphabetic),
+    (0xFB13, 0xFB17, CharClass::Alphabetic),
+    (0xFB1D, 0xFB28, CharClass::Alphabetic),
+    (0xFB29, 0xFB29, CharClass::Symbol),
+    (0xFB2A, 0xFB36, CharClass::Alphabetic),
+    (0xFB38, 0xFB3C, CharClass::Alphabetic),
+    (0xFB3E, 0xFB3E, CharClass::Alphabetic),
+    (0xFB40, 0xFB41, CharClass::Alphabetic),
+    (0xFB43, 0xFB44, CharClass::Alphabetic),
+    (0xFB46, 0xFBB1, CharClass::Alphabetic),
+    (0xFBB2, 0xFBC2, CharClass::Symbol),
+    (0xFBD3, 0xFD3D, CharClass::Alphabetic),
+    (0xFD3E, 0xFD3F, CharClass::Punctuation),
+    (0xFD40, 0xFD4F, CharClass::Symbol),
+    (0xFD50, 0xFD8F, CharClass::Alphabetic),
+    (0xFD92, 0xFDC7, CharClass::Alphabetic),
+    (0xFDCF, 0xFDCF, CharClass::Symbol),
+    (0xFDF0, 0xFDFB, CharClass::Alphabetic),
+    (0xFDFC, 0xFDFF, CharClass::Symbol),
+    (0xFE10, 0xFE19, CharClass::Punctuation),
+    (0xFE30, 0xFE52, CharClass::Punctuation),
+    (0xFE54, 0xFE61, CharClass::Punctuation),
+    (0xFE62, 0xFE62, CharClass::Symbol),
+    (0xFE63, 0xFE63, CharClass::Punctuation),
+    (0xFE64, 0xFE66, CharClass::Symbol),
+    (0xFE68, 0xFE68, CharClass::Punctuation),
+    (0xFE69, 0xFE69, CharClass::Symbol),
+    (0xFE6A, 0xFE6B, CharClass::Punctuation),
+    (0xFE70, 0xFE74, CharClass::Alphabetic),
+    (0xFE76, 0xFEFC, CharClass::Alphabetic),
+    (0xFF01, 0xFF03, CharClass::Punctuation),
+    (0xFF04, 0xFF04, CharClass::Symbol),
+    (0xFF05, 0xFF0A, CharClass::Punctuation),
+    (0xFF0B, 0xFF0B, CharClass::Symbol),
+    (0xFF0C, 0xFF0F, CharClass::Punctuation),
+    (0xFF10, 0xFF19, CharClass::Numeric),
+    (0xFF1A, 0xFF1B, CharClass::Punctuation),
+    (0xFF1C, 0xFF1E, CharClass::Symbol),
+    (0xFF1F, 0xFF20, CharClass::Punctuation),
+    (0xFF21, 0xFF3A, CharClass::Alphabetic),
+    (0xFF3B, 0xFF3D, CharClass::Punctuation),
+    (0xFF3E, 0xFF3E, CharClass::Symbol),
+    (0xFF3F, 0xFF3F, CharClass::Punctuation),
+    (0xFF40, 0xFF40, CharClass::Symbol),
+    (0xFF41, 0xFF5A, CharClass::Alphabetic),
+    (0xFF5B, 0xFF5B, CharClass::Punctuation),
+    (0xFF5C, 0xFF5C, CharClass::Symbol),
+    (0xFF5D, 0xFF5D, CharClass::Punctuation),
+    (0xFF5E, 0xFF5E, CharClass::Symbol),
+    (0xFF5F, 0xFF65, CharClass::Punctuation),
+    (0xFF66, 0xFFBE, CharClass::Alphabetic),
+    (0xFFC2, 0xFFC7, CharClass::Alphabetic),
+    (0xFFCA, 0xFFCF, CharClass::Alphabetic),
+    (0xFFD2, 0xFFD7, CharClass::Alphabetic),
+    (0xFFDA, 0xFFDC, CharClass::Alphabetic),
+    (0xFFE0, 0xFFE6, CharClass::Symbol),
+    (0xFFE8, 0xFFEE, CharClass::Symbol),
+    (0xFFFC, 0xFFFD, CharClass::Symbol),
+    (0x10000, 0x1000B, CharClass::Alphabetic),
+    (0x1000D, 0x10026, CharClass::Alphabetic),
+    (0x10028, 0x1003A, CharClass::Alphabetic),
+    (0x1003C, 0x1003D, CharClass::Alphabetic),
+    (0x1003F, 0x1004D, CharClass::Alphabetic),
+    (0x10050, 0x1005D, CharClass::Alphabetic),
+    (0x10080, 0x100FA, CharClass::Alphabetic),
+    (0x10100, 0x10102, CharClass::Punctuation),
+    (0x10107, 0x10133, CharClass::Numeric),
+    (0x10137, 0x1013F, CharClass::Symbol),
+    (0x10140, 0x10174, CharClass::Alphabetic),
+    (0x10175, 0x10178, CharClass::Numeric),
+    (0x10179, 0x10189, CharClass::Symbol),
+    (0x1018A, 0x1018B, CharClass::Numeric),
+    (0x1018C, 0x1018E, CharClass::Symbol),
+    (0x10190, 0x1019C, CharClass::Symbol),
+    (0x101A0, 0x101A0, CharClass::Symbol),
+    (0x101D0, 0x101FC, CharClass::Symbol),
+    (0x10280, 0x1029C, CharClass::Alphabetic),
+    (0x102A0, 0x102D0, CharClass::Alphabetic),
+    (0x102E1, 0x102FB, CharClass::Numeric),
+    (0x10300, 0x1031F, CharClass::Alphabetic),
+    (0x10320, 0x10323, CharClass::Numeric),
+    (0x1032D, 0x1034A, CharClass::Alphabetic),
+    (0x10350, 0x1037A, CharClass::Alphabetic),
+    (0x10380, 0x1039D, CharClass::Alphabetic),
+    (0x1039F, 0x1039F, CharClass::Punctuation),
+    (0x103A0, 0x103C3, CharClass::Alphabetic),
+    (0x103C8, 0x103CF, CharClass::Alphabetic),
+    (0x103D0, 0x103D0, CharClass::Punctuation),
+    (0x103D1, 0x103D5, CharClass::Alphabetic),
+    (0x10400, 0x1049D, CharClass::Alphabetic),
+    (0x104A0, 0x104A9, CharClass::Numeric),
+    (0x104B0, 0x104D3, CharClass::Alphabetic),
+    (0x104D8, 0x104FB, CharClass::Alphabetic),
+    (0x10500, 0x10527, CharClass::Alphabetic),
+    (0x10530, 0x10563, CharClass::Alphabetic),
+    (0x1056F, 0x1056F, CharClass::Punctuation),
+    (0x10570, 0x1057A, CharClass::Alphabetic),
+    (0x1057C, 0x1058A, CharClass::Alphabetic),
+    (0x1058C, 0x10592, CharClass::Alphabetic),
+    (0x10594, 0x10595, CharClass::Alphabetic),
+    (0x10597, 0x105A1, CharClass::Alphabetic),
+    (0x105A3, 0x105B1, CharClass::Alphabetic),
+    (0x105B3, 0x105B9, CharClass::Alphabetic),
+    (0x105BB, 0x105BC, CharClass::Alphabetic),
+    (0x10600, 0x10736, CharClass::Alphabetic),
+    (0x10740, 0x10755, CharClass::Alphabetic),
+    (0x10760, 0x10767, CharClass::Alphabetic),
+    (0x10780, 0x10785, CharClass::Alphabetic),
+    (0x10787, 0x107B0, CharClass::Alphabetic),
+    (0x107B2, 0x107BA, CharClass::Alphabetic),
+    (0x10800, 0x10805, CharClass::Alphabetic),
+    (0x10808, 0x10808, CharClass::Alphabetic),
+    (0x1080A, 0x10835, CharClass::Alphabetic),
+    (0x10837, 0x10838, CharClass::Alphabetic),
+    (0x1083C, 0x1083C, CharClass::Alphabetic),
+    (0x1083F, 0x10855, CharClass::Alphabetic),
+    (0x10857, 0x10857, CharClass::Punctuation),
+    (0x10858, 0x1085F, CharClass::Numeric),
+    (0x10860, 0x10876, CharClass::Alphabetic),
+    (0x10877, 0x10878, CharClass::Symbol),
+    (0x10879, 0x1087F, CharClass::Numeric),
+    (0x10880, 0x1089E, CharClass::Alphabetic),
+    (0x108A7, 0x108AF, CharClass::Numeric),
+    (0x108E0, 0x108F2, CharClass::Alphabetic),
+    (0x108F4, 0x108F5, CharClass::Alphabetic),
+    (0x108FB, 0x108FF, CharClass::Numeric),
+    (0x10900, 0x10915, CharClass::Alphabetic),
+    (0x10916, 0x1091B, CharClass::Numeric),
+    (0x1091F, 0x1091F, CharClass::Punctuation),
+    (0x10920, 0x10939, CharClass::Alphabetic),
+    (0x1093F, 0x1093F, CharClass::Punctuation),
+    (0x10980, 0x109B7, CharClass::Alphabetic),
+    (0x109BC, 0x109BD, CharClass::Numeric),
+    (0x109BE, 0x109BF, CharClass::Alphabetic),
+    (0x109C0, 0x109CF, CharClass::Numeric),
+    (0x109D2, 0x109FF, CharClass::Numeric),
+    (0x10A00, 0x10A03, CharClass::Alphabetic),
+    (0x10A05, 0x10A06, CharClass::Alphabetic),
+    (0x10A0C, 0x10A13, CharClass::Alphabetic),
+    (0x10A15, 0x10A17, CharClass::Alphabetic),
+    (0x10A19, 0x10A35, CharClass::Alphabetic),
+    (0x10A40, 0x10A48, CharClass::Numeric),
+    (0x10A50, 0x10A58, CharClass::Punctuation),
+    (0x10A60, 0x10A7C, CharClass::Alphabetic),
+    (0x10A7D, 0x10A7E, CharClass::Numeric),
+    (0x10A7F, 0x10A7F, CharClass::Punctuation),
+    (0x10A80, 0x10A9C, CharClass::Alphabetic),
+    (0x10A9D, 0x10A9F, CharClass::Numeric),
+    (0x10AC0, 0x10AC7, CharClass::Alphabetic),
+    (0x10AC8, 0x10AC8, CharClass::Symbol),
+    (0x10AC9, 0x10AE4, CharClass::Alphabetic),
+    (0x10AEB, 0x10AEF, CharClass::Numeric),
+    (0x10AF0, 0x10AF6, CharClass::Punctuation),
+    (0x10B00, 0x10B35, CharClass::Alphabetic),
+    (0x10B39, 0x10B3F, CharClass::Punctuation),
+    (0x10B40, 0x10B55, CharClass::Alphabetic),
+    (0x10B58, 0x10B5F, CharClass::Numeric),
+    (0x10B60, 0x10B72, CharClass::Alphabetic),
+    (0x10B78, 0x10B7F, CharClass::Numeric),
+    (0x10B80, 0x10B91, CharClass::Alphabetic),
+    (0x10B99, 0x10B9C, CharClass::Punctuation),
+    (0x10BA9, 0x10BAF, CharClass::Numeric),
+    (0x10C00, 0x10C48, CharClass::Alphabetic),
+    (0x10C80, 0x10CB2, CharClass::Alphabetic),
+    (0x10CC0, 0x10CF2, CharClass::Alphabetic),
+    (0x10CFA, 0x10CFF, CharClass::Numeric),
+    (0x10D00, 0x10D27, CharClass::Alphabetic),
+    (0x10D30, 0x10D39, CharClass::Numeric),
+    (0x10E60, 0x10E7E, CharClass::Numeric),
+    (0x10E80, 0x10EA9, CharClass::Alphabetic),
+    (0x10EAB, 0x10EAC, CharClass::Alphabetic),
+    (0x10EAD, 0x10EAD, CharClass::Punctuation),
+    (0x10EB0, 0x10EB1, CharClass::Alphabetic),
+    (0x10F00, 0x10F1C, CharClass::Alphabetic),
+    (0x10F1D, 0x10F26, CharClass::Numeric),
+    (0x10F27, 0x10F27, CharClass::Alphabetic),
+    (0x10F30, 0x10F45, CharClass::Alphabetic),
+    (0x10F51, 0x10F54, CharClass::Numeric),
+    (0x10F55, 0x10F59, CharClass::Punctuation),
+    (0x10F70, 0x10F81, CharClass::Alphabetic),
+    (0x10F86, 0x10F89, CharClass::Punctuation),
+    (0x10FB0, 0x10FC4, CharClass::Alphabetic),
+    (0x10FC5, 0x10FCB, CharClass::Numeric),
+    (0x10FE0, 0x10FF6, CharClass::Alphabetic),
+    (0x11000, 0x11045, CharClass::Alphabetic),
+    (0x11047, 0x1104D, CharClass::Punctuation),
+    (0x11052, 0x1106F, CharClass::Numeric),
+    (0x11071, 0x11075, CharClass::Alphabetic),
+    (0x11082, 0x110B8, CharClass::Alphabetic),
+    (0x110BB, 0x110BC, CharClass::Punctuation),
+    (0x110BE, 0x110C1, CharClass::Punctuation),
+    (0x110C2, 0x110C2, CharClass::Alphabetic),
+    (0x110D0, 0x110E8, CharClass::Alphabetic),
+    (0x110F0, 0x110F9, CharClass::Numeric),
+    (0x11100, 0x11132, CharClass::Alphabetic),
+    (0x11136, 0x1113F, CharClass::Numeric),
+    (0x11140, 0x11143, CharClass::Punctuation),
+    (0x11144, 0x11147, CharClass::Alphabetic),
+    (0x11150, 0x11172, CharClass::Alphabetic),
+    (0x11174, 0x11175, CharClass::Punctuation),
+    (0x11176, 0x11176, CharClass::Alphabetic),
+    (0x11180, 0x111BF, CharClass::Alphabetic),
+    (0x111C1, 0x111C4, CharClass::Alphabetic),
+    (0x111C5, 0x111C8, CharClass::Punctuation),
+    (0x111CD, 0x111CD, CharClass::Punctuation),
+    (0x111CE, 0x111CF, CharClass::Alphabetic),
+    (0x111D0, 0x111D9, CharClass::Numeric),
+    (0x111DA, 0x111DA, CharClass::Alphabetic),
+    (0x111DB, 0x111DB, CharClass::Punctuation),
+    (0x111DC, 0x111DC, CharClass::Alphabetic),
+    (0x111DD, 0x111DF, CharClass::Punctuation),
+    (0x111E1, 0x111F4, CharClass::Numeric),
+    (0x11200, 0x11211, CharClass::Alphabetic),
+    (0x11213, 0x11234, CharClass::Alphabetic),
+    (0x11237, 0x11237, CharClass::Alphabetic),
+    (0x11238, 0x1123D, CharClass::Punctuation),
+    (0x1123E, 0x1123E, CharClass::Alphabetic),
+    (0x11280, 0x11286, CharClass::Alphabetic),
+    (0x11288, 0x11288, CharClass::Alphabetic),
+    (0x1128A, 0x1128D, CharClass::Alphabetic),
+    (0x1128F, 0x1129D, CharClass::Alphabetic),
+    (0x1129F, 0x112A8, CharClass::Alphabetic),
+    (0x112A9, 0x112A9, CharClass::Punctuation),
+    (0x112B0, 0x112E8, CharClass::Alphabetic),
+    (0x112F0, 0x112F9, CharClass::Numeric),
+    (0x11300, 0x11303, CharClass::Alphabetic),
+    (0x11305, 0x1130C, CharClass::Alphabetic),
+    (0x1130F, 0x11310, CharClass::Alphabetic),
+    (0x11313, 0x11328, CharClass::Alphabetic),
+    (0x1132A, 0x11330, CharClass::Alphabetic),
+    (0x11332, 0x11333, CharClass::Alphabetic),
+    (0x11335, 0x11339, CharClass::Alphabetic),
+    (0x1133D, 0x11344, CharClass::Alphabetic),
+    (0x11347, 0x11348, CharClass::Alphabetic),
+    (0x1134B, 0x1134C, CharClass::Alphabetic),
+    (0x11350, 0x11350, CharClass::Alphabetic),
+    (0x11357, 0x11357, CharClass::Alphabetic),
+    (0x1135D, 0x11363, CharClass::Alphabetic),
+    (0x11400, 0x11441, CharClass::Alphabetic),
+    (0x11443, 0x11445, CharClass::Alphabetic),
+    (0x11447, 0x1144A, CharClass::Alphabetic),
+    (0x1144B, 0x1144F, CharClass::Punctuation),
+    (0x11450, 0x11459, CharClass::Numeric),
+    (0x1145A, 0x1145B, CharClass::Punctuation),
+    (0x1145D, 0x1145D, CharClass::Punctuation),
+    (0x1145F, 0x11461, CharClass::Alphabetic),
+    (0x11480, 0x114C1, CharClass::Alphabetic),
+    (0x114C4, 0x114C5, CharClass::Alphabetic),
+    (0x114C6, 0x114C6, CharClass::Punctuation),
+    (0x114C7, 0x114C7, CharClass::Alphabetic),
+    (0x114D0, 0x114D9, CharClass::Numeric),
+    (0x11580, 0x115B5, CharClass::Alphabetic),
+    (0x115B8, 0x115BE, CharClass::Alphabetic),
+    (0x115C1, 0x115D7, CharClass::Punctuation),
+    (0x115D8, 0x115DD, CharClass::Alphabetic),
+    (0x11600, 0x1163E, CharClass::Alphabetic),
+    (0x11640, 0x11640, CharClass::Alphabetic),
+    (0x11641, 0x11643, CharClass::Punctuation),
+    (0x11644, 0x11644, CharClass::Alphabetic),
+    (0x11650, 0x11659, CharClass::Numeric),
+    (0x11660, 0x1166C, CharClass::Punctuation),
+    (0x11680, 0x116B5, CharClass::Alphabetic),
+    (0x116B8, 0x116B8, CharClass::Alphabetic),
+    (0x116B9, 0x116B9, CharClass::Punctuation),
+    (0x116C0, 0x116C9, CharClass::Numeric),
+    (0x11700, 0x1171A, CharClass::Alphabetic),
+    (0x1171D, 0x1172A, CharClass::Alphabetic),
+    (0x11730, 0x1173B, CharClass::Numeric),
+    (0x1173C, 0x1173E, CharClass::Punctuation),
+    (0x1173F, 0x1173F, CharClass::Symbol),
+    (0x11740, 0x11746, CharClass::Alphabetic),
+    (0x11800, 0x11838, CharClass::Alphabetic),
+    (0x1183B, 0x1183B, CharClass::Punctuation),
+    (0x118A0, 0x118DF, CharClass::Alphabetic),
+    (0x118E0, 0x118F2, CharClass::Numeric),
+    (0x118FF, 0x11906, CharClass::Alphabetic),
+    (0x11909, 0x11909, CharClass::Alphabetic),
+    (0x1190C, 0x11913, CharClass::Alphabetic),
+    (0x11915, 0x11916, CharClass::Alphabetic),
+    (0x11918, 0x11935, CharClass::Alphabetic),
+    (0x11937, 0x11938, CharClass::Alphabetic),
+    (0x1193B, 0x1193C, CharClass::Alphabetic),
+    (0x1193F, 0x11942, CharClass::Alphabetic),
+    (0x11944, 0x11946, CharClass::Punctuation),
+    (0x11950, 0x11959, CharClass::Numeric),
+    (0x119A0, 0x119A7, CharClass::Alphabetic),
+    (0x119AA, 0x119D7, CharClass::Alphabetic),
+    (0x119DA, 0x119DF, CharClass::Alphabetic),
+    (0x119E1, 0x119E1, CharClass::Alphabetic),
+    (0x119E2, 0x119E2, CharClass::Punctuation),
+    (0x119E3, 0x119E4, CharClass::Alphabetic),
+    (0x11A00, 0x11A32, CharClass::Alphabetic),
+    (0x11A35, 0x11A3E, CharClass::Alphabetic),
+    (0x11A3F, 0x11A46, CharClass::Punctuation),
+    (0x11A50, 0x11A97, CharClass::Alphabetic),
+    (0x11A9A, 0x11A9C, CharClass::Punctuation),
+    (0x11A9D, 0x11A9D, CharClass::Alphabetic),
+    (0x11A9E, 0x11AA2, CharClass::Punctuation),
+    (0x11AB0, 0x11AF8, CharClass::Alphabetic),
+    (0x11C00, 0x11C08, CharClass::Alphabetic),
+    (0x11C0A, 0x11C36, CharClass::Alphabetic),
+    (0x11C38, 0x11C3E, CharClass::Alphabetic),
+    (0x11C40, 0x11C40, CharClass::Alphabetic),
+    (0x11C41, 0x11C45, CharClass::Punctuation),
+    (0x11C50, 0x11C6C, CharClass::Numeric),
+    (0x11C70, 0x11C71, CharClass::Punctuation),
+    (0x11C72, 0x11C8F, CharClass::Alphabetic),
+    (0x11C92, 0x11CA7, CharClass::Alphabetic),
+    (0x11CA9, 0x11CB6, CharClass::Alphabetic),
+    (0x11D00, 0x11D06, CharClass::Alphabetic),
+    (0x11D08, 0x11D09, CharClass::Alphabetic),
+    (0x11D0B, 0x11D36, CharClass::Alphabetic),
+    (0x11D3A, 0x11D3A, CharClass::Alphabetic),
+    (0x11D3C, 0x11D3D, CharClass::Alphabetic),
+    (0x11D3F, 0x11D41, CharClass::Alphabetic),
+    (0x11D43, 0x11D43, CharClass::Alphabetic),
+    (0x11D46, 0x11D47, CharClass::Alphabetic),
+    (0x11D50, 0x11D59, CharClass::Numeric),
+    (0x11D60, 0x11D65, CharClass::Alphabetic),
+    (0x11D67, 0x11D68, CharClass::Alphabetic),
+    (0x11D6A, 0x11D8E, CharClass::Alphabetic),
+    (0x11D90, 0x11D91, CharClass::Alphabetic),
+    (0x11D93, 0x11D96, CharClass::Alphabetic),
+    (0x11D98, 0x11D98, CharClass::Alphabetic),
+    (0x11DA0, 0x11DA9, CharClass::Numeric),
+    (0x11EE0, 0x11EF6, CharClass::Alphabetic),
+    (0x11EF7, 0x11EF8, CharClass::Punctuation),
+    (0x11FB0, 0x11FB0, CharClass::Alphabetic),
+    (0x11FC0, 0x11FD4, CharClass::Numeric),
+    (0x11FD5, 0x11FF1, CharClass::Symbol),
+    (0x11FFF, 0x11FFF, CharClass::Punctuation),
+    (0x12000, 0x12399, CharClass::Alphabetic),
+    (0x12400, 0x1246E, CharClass::Alphabetic),
+    (0x12470, 0x12474, CharClass::Punctuation),
+    (0x12480, 0x12543, CharClass::Alphabetic),
+    (0x12F90, 0x12FF0, CharClass::Alphabetic),
+    (0x12FF1, 0x12FF2, CharClass::Punctuation),
+    (0x13000, 0x1342E, CharClass::Alphabetic),
+    (0x14400, 0x14646, CharClass::Alphabetic),
+    (0x16800, 0x16A38, CharClass::Alphabetic),
+    (0x16A40, 0x16A5E, CharClass::Alphabetic),
+    (0x16A60, 0x16A69, CharClass::Numeric),
+    (0x16A6E, 0x16A6F, CharClass::Punctuation),
+    (0x16A70, 0x16ABE, CharClass::Alphabetic),
+    (0x16AC0, 0x16AC9, CharClass::Numeric),
+    (0x16AD0, 0x16AED, CharClass::Alphabetic),
+    (0x16AF5, 0x16AF5, CharClass::Punctuation),
+    (0x16B00, 0x16B2F, CharClass::Alphabetic),
+    (0x16B37, 0x16B3B, CharClass::Punctuation),
+    (0x16B3C, 0x16B3F, CharClass::Symbol),
+    (0x16B40, 0x16B43, CharClass::Alphabetic),
+    (0x16B44, 0x16B44, CharClass::Punctuation),
+    (0x16B45, 0x16B45, CharClass::Symbol),
+    (0x16B50, 0x16B59, CharClass::Numeric),
+    (0x16B5B, 0x16B61, CharClass::Numeric),
+    (0x16B63, 0x16B77, CharClass::Alphabetic),
+    (0x16B7D, 0x16B8F, CharClass::Alphabetic),
+    (0x16E40, 0x16E7F, CharClass::Alphabetic),
+    (0x16E80, 0x16E96, CharClass::Numeric),
+    (0x16E97, 0x16E9A, CharClass::Punctuation),
+    (0x16F00, 0x16F4A, CharClass::Alphabetic),
+    (0x16F4F, 0x16F87, CharClass::Alphabetic),
+    (0x16F8F, 0x16F9F, CharClass::Alphabetic),
+    (0x16FE0, 0x16FE1, CharClass::Alphabetic),
+    (0x16FE2, 0x16FE2, CharClass::Punctuation),
+    (0x16FE3, 0x16FE3, CharClass::Alphabetic),
+    (0x16FF0, 0x16FF1, CharClass::Alphabetic),
+    (0x17000, 0x187F7, CharClass::Alphabetic),
+    (0x18800, 0x18CD5, CharClass::Alphabetic),
+    (0x18D00, 0x18D08, CharClass::Alphabetic),
+    (0x1AFF0, 0x1AFF3, CharClass::Alphabetic),
+    (0x1AFF5, 0x1AFFB, CharClass::Alphabetic),
+    (0x1AFFD, 0x1AFFE, CharClass::Alphabetic),
+    (0x1B000, 0x1B122, CharClass::Alphabetic),
+    (0x1B150, 0x1B152, CharClass::Alphabetic),
+    (0x1B164, 0x1B167, CharClass::Alphabetic),
+    (0x1B170, 0x1B2FB, CharClass::Alphabetic),
+    (0x1BC00, 0x1BC6A, CharClass::Alphabetic),
+    (0x1BC70, 0x1BC7C, CharClass::Alphabetic),
+    (0x1BC80, 0x1BC88, CharClass::Alphabetic),
+    (0x1BC90, 0x1BC99, CharClass::Alphabetic),
+    (0x1BC9C, 0x1BC9C, CharClass::Symbol),
+    (0x1BC9E, 0x1BC9E, CharClass::Alphabetic),
+    (0x1BC9F, 0x1BC9F, CharClass::Punctuation),
+    (0x1CF50, 0x1CFC3, CharClass::Symbol),
+    (0x1D000, 0x1D0F5, CharClass::Symbol),
+    (0x1D100, 0x1D126, CharClass::Symbol),
+    (0x1D129, 0x1D164, CharClass::Symbol),
+    (0x1D16A, 0x1D16C, CharClass::Symbol),
+    (0x1D183, 0x1D184, CharClass::Symbol),
+    (0x1D18C, 0x1D1A9, CharClass::Symbol),
+    (0x1D1AE, 0x1D1EA, CharClass::Symbol),
+    (0x1D200, 0x1D241, CharClass::Symbol),
+    (0x1D245, 0x1D245, CharClass::Symbol),
+    (0x1D2E0, 0x1D2F3, CharClass::Numeric),
+    (0x1D300, 0x1D356, CharClass::Symbol),
+    (0x1D360, 0x1D378, CharClass::Numeric),
+    (0x1D400, 0x1D454, CharClass::Alphabetic),
+    (0x1D456, 0x1D49C, CharClass::Alphabetic),
+    (0x1D49E, 0x1D49F, CharClass::Alphabetic),
+    (0x1D4A2, 0x1D4A2, CharClass::Alphabetic),
+    (0x1D4A5, 0x1D4A6, CharClass::Alphabetic),
+    (0x1D4A9, 0x1D4AC, CharClass::Alphabetic),
+    (0x1D4AE, 0x1D4B9, CharClass::Alphabetic),
+    (0x1D4BB, 0x1D4BB, CharClass::Alphabetic),
+    (0x1D4BD, 0x1D4C3, CharClass::Alphabetic),
+    (0x1D4C5, 0x1D505, CharClass::Alphabetic),
+    (0x1D507, 0x1D50A, CharClass::Alphabetic),
+    (0x1D50D, 0x1D514, CharClass::Alphabetic),
+    (0x1D516, 0x1D51C, CharClass::Alphabetic),
+    (0x1D51E, 0x1D539, CharClass::Alphabetic),
+    (0x1D53B, 0x1D53E, CharClass::Alphabetic),
+    (0x1D540, 0x1D544, CharClass::Alphabetic),
+    (0x1D546, 0x1D546, CharClass::Alphabetic),
+    (0x1D54A, 0x1D550, CharClass::Alphabetic),
+    (0x1D552, 0x1D6A5, CharClass::Alphabetic),
+    (0x1D6A8, 0x1D6C0, CharClass::Alphabetic),
+    (0x1D6C1, 0x1D6C1, CharClass::Symbol),
+    (0x1D6C2, 0x1D6DA, CharClass::Alphabetic),
+    (0x1D6DB, 0x1D6DB, CharClass::Symbol),
+    (0x1D6DC, 0x1D6FA, CharClass::Alphabetic),
+    (0x1D6FB, 0x1D6FB, CharClass::Symbol),
+    (0x1D6FC, 0x1D714, CharClass::Alphabetic),
+    (0x1D715, 0x1D715, CharClass::Symbol),
+    (0x1D716, 0x1D734, CharClass::Alphabetic),
+    (0x1D735, 0x1D735, CharClass::Symbol),
+    (0x1D736, 0x1D74E, CharClass::Alphabetic),
+    (0x1D74F, 0x1D74F, CharClass::Symbol),
+    (0x1D750, 0x1D76E, CharClass::Alphabetic),
+    (0x1D76F, 0x1D76F, CharClass::Symbol),
+    (0x1D770, 0x1D788, CharClass::Alphabetic),
+    (0x1D789, 0x1D789, CharClass::Symbol),
+    (0x1D78A, 0x1D7A8, CharClass::Alphabetic),
+    (0x1D7A9, 0x1D7A9, CharClass::Symbol),
+    (0x1D7AA, 0x1D7C2, CharClass::Alphabetic),
+    (0x1D7C3, 0x1D7C3, CharClass::Symbol),
+    (0x1D7C4, 0x1D7CB, CharClass::Alphabetic),
+    (0x1D7CE, 0x1D7FF, CharClass::Numeric),
+    (0x1D800, 0x1D9FF, CharClass::Symbol),
+    (0x1DA37, 0x1DA3A, CharClass::Symbol),
+    (0x1DA6D, 0x1DA74, CharClass::Symbol),
+    (0x1DA76, 0x1DA83, CharClass::Symbol),
+    (0x1DA85, 0x1DA86, CharClass::Symbol),
+    (0x1DA87, 0x1DA8B, CharClass::Punctuation),
+    (0x1DF00, 0x1DF1E, CharClass::Alphabetic),
+    (0x1E000, 0x1E006, CharClass::Alphabetic),
+    (0x1E008, 0x1E018, CharClass::Alphabetic),
+    (0x1E01B, 0x1E021, CharClass::Alphabetic),
+    (0x1E023, 0x1E024, CharClass::Alphabetic),
+    (0x1E026, 0x1E02A, CharClass::Alphabetic),
+    (0x1E100, 0x1E12C, CharClass::Alphabetic),
+    (0x1E137, 0x1E13D, CharClass::Alphabetic),
+    (0x1E140, 0x1E149, CharClass::Numeric),
+    (0x1E14E, 0x1E14E, CharClass::Alphabetic),
+    (0x1E14F, 0x1E14F, CharClass::Symbol),
+    (0x1E290, 0x1E2AD, CharClass::Alphabetic),
+    (0x1E2C0, 0x1E2EB, CharClass::Alphabetic),
+    (0x1E2F0, 0x1E2F9, CharClass::Numeric),
+    (0x1E2FF, 0x1E2FF, CharClass::Symbol),
+    (0x1E7E0, 0x1E7E6, CharClass::Alphabetic),
+    (0x1E7E8, 0x1E7EB, CharClass::Alphabetic),
+    (0x1E7ED, 0x1E7EE, CharClass::Alphabetic),
+    (0x1E7F0, 0x1E7FE, CharClass::Alphabetic),
+    (0x1E800, 0x1E8C4, CharClass::Alphabetic),
+    (0x1E8C7, 0x1E8CF, CharClass::Numeric),
+    (0x1E900, 0x1E943, CharClass::Alphabetic),
+    (0x1E947, 0x1E947, CharClass::Alphabetic),
+    (0x1E94B, 0x1E94B, CharClass::Alphabetic),
+    (0x1E950, 0x1E959, CharClass::Numeric),
+    (0x1E95E, 0x1E95F, CharClass::Punctuation),
+    (0x1EC71, 0x1ECAB, CharClass::Numeric),
+    (0x1ECAC, 0x1ECAC, CharClass::Symbol),
+    (0x1ECAD, 0x1ECAF, CharClass::Numeric),
+    (0x1ECB0, 0x1ECB0, CharClass::Symbol),
+    (0x1ECB1, 0x1ECB4, CharClass::Numeric),
+    (0x1ED01, 0x1ED2D, CharClass::Numeric),
+    (0x1ED2E, 0x1ED2E, CharClass::Symbol),
+    (0x1ED2F, 0x1ED3D, CharClass::Numeric),
+    (0x1EE00, 0x1EE03, CharClass::Alphabetic),
+    (0x1EE05, 0x1EE1F, CharClass::Alphabetic),
+    (0x1EE21, 0x1EE22, CharClass::Alphabetic),
+    (0x1EE24, 0x1EE24, CharClass::Alphabetic),
+    (0x1EE27, 0x1EE27, CharClass::Alphabetic),
+    (0x1EE29, 0x1EE32, CharClass::Alphabetic),
+    (0x1EE34, 0x1EE37, CharClass::Alphabetic),
+    (0x1EE39, 0x1EE39, CharClass::Alphabetic),
+    (0x1EE3B, 0x1EE3B, CharClass::Alphabetic),
+    (0x1EE42, 0x1EE42, CharClass::Alphabetic),
+    (0x1EE47, 0x1EE47, CharClass::Alphabetic),
+    (0x1EE49, 0x1EE49, CharClass::Alphabetic),
+    (0x1EE4B, 0x1EE4B, CharClass::Alphabetic),
+    (0x1EE4D, 0x1EE4F, CharClass::Alphabetic),
+    (0x1EE51, 0x1EE52, CharClass::Alphabetic),
+    (0x1EE54, 0x1EE54, CharClass::Alphabetic),
+    (0x1EE57, 0x1EE57, CharClass::Alphabetic),
+    (0x1EE59, 0x1EE59, CharClass::Alphabetic),
+    (0x1EE5B, 0x1EE5B, CharClass::Alphabetic),
+    (0x1EE5D, 0x1EE5D, CharClass::Alphabetic),
+    (0x1EE5F, 0x1EE5F, CharClass::Alphabetic),
+    (0x1EE61, 0x1EE62, CharClass::Alphabetic),
+    (0x1EE64, 0x1EE64, CharClass::Alphabetic),
+    (0x1EE67, 0x1EE6A, CharClass::Alphabetic),
+    (0x1EE6C, 0x1EE72, CharClass::Alphabetic),
+    (0x1EE74, 0x1EE77, CharClass::Alphabetic),
+    (0x1EE79, 0x1EE7C, CharClass::Alphabetic),
+    (0x1EE7E, 0x1EE7E, CharClass::Alphabetic),
+    (0x1EE80, 0x1EE89, CharClass::Alphabetic),
+    (0x1EE8B, 0x1EE9B, CharClass::Alphabetic),
+    (0x1EEA1, 0x1EEA3, CharClass::Alphabetic),
+    (0x1EEA5, 0x1EEA9, CharClass::Alphabetic),
+    (0x1EEAB, 0x1EEBB, CharClass::Alphabetic),
+    (0x1EEF0, 0x1EEF1, CharClass::Symbol),
+    (0x1F000, 0x1F02B, CharClass::Symbol),
+    (0x1F030, 0x1F093, CharClass::Symbol),
+    (0x1F0A0, 0x1F0AE, CharClass::Symbol),
+    (0x1F0B1, 0x1F0BF, CharClass::Symbol),
+    (0x1F0C1, 0x1F0CF, CharClass::Symbol),
+    (0x1F0D1, 0x1F0F5, CharClass::Symbol),
+    (0x1F100, 0x1F10C, CharClass::Numeric),
+    (0x1F10D, 0x1F12F, CharClass::Symbol),
+    (0x1F130, 0x1F149, CharClass::Alphabetic),
+    (0x1F14A, 0x1F14F, CharClass::Symbol),
+    (0x1F150, 0x1F169, CharClass::Alphabetic),
+    (0x1F16A, 0x1F16F, CharClass::Symbol),
+    (0x1F170, 0x1F189, CharClass::Alphabetic),
+    (0x1F18A, 0x1F1AD, CharClass::Symbol),
+    (0x1F1E6, 0x1F202, CharClass::Symbol),
+    (0x1F210, 0x1F23B, CharClass::Symbol),
+    (0x1F240, 0x1F248, CharClass::Symbol),
+    (0x1F250, 0x1F251, CharClass::Symbol),
+    (0x1F260, 0x1F265, CharClass::Symbol),
+    (0x1F300, 0x1F6D7, CharClass::Symbol),
+    (0x1F6DD, 0x1F6EC, CharClass::Symbol),
+    (0x1F6F0, 0x1F6FC, CharClass::Symbol),
+    (0x1F700, 0x1F773, CharClass::Symbol),
+    (0x1F780, 0x1F7D8, CharClass::Symbol),
+    (0x1F7E0, 0x1F7EB, CharClass::Symbol),
+    (0x1F7F0, 0x1F7F0, CharClass::Symbol),
+    (0x1F800, 0x1F80B, CharClass::Symbol),
+    (0x1F810, 0x1F847, CharClass::Symbol),
+    (0x1F850, 0x1F859, CharClass::Symbol),
+    (0x1F860, 0x1F887, CharClass::Symbol),
+    (0x1F890, 0x1F8AD, CharClass::Symbol),
+    (0x1F8B0, 0x1F8B1, CharClass::Symbol),
+    (0x1F900, 0x1FA53, CharClass::Symbol),
+    (0x1FA60, 0x1FA6D, CharClass::Symbol),
+    (0x1FA70, 0x1FA74, CharClass::Symbol),
+    (0x1FA78, 0x1FA7C, CharClass::Symbol),
+    (0x1FA80, 0x1FA86, CharClass::Symbol),
+    (0x1FA90, 0x1FAAC, CharClass::Symbol),
+    (0x1FAB0, 0x1FABA, CharClass::Symbol),
+    (0x1FAC0, 0x1FAC5, CharClass::Symbol),
+    (0x1FAD0, 0x1FAD9, CharClass::Symbol),
+    (0x1FAE0, 0x1FAE7, CharClass::Symbol),
+    (0x1FAF0, 0x1FAF6, CharClass::Symbol),
+    (0x1FB00, 0x1FB92, CharClass::Symbol),
+    (0x1FB94, 0x1FBCA, CharClass::Symbol),
+    (0x1FBF0, 0x1FBF9, CharClass::Numeric),
+    (0x20000, 0x2A6DF, CharClass::Alphabetic),
+    (0x2A700, 0x2B738, CharClass::Alphabetic),
+    (0x2B740, 0x2B81D, CharClass::Alphabetic),
+    (0x2B820, 0x2CEA1, CharClass::Alphabetic),
+    (0x2CEB0, 0x2EBE0, CharClass::Alphabetic),
+    (0x2F800, 0x2FA1D, CharClass::Alphabetic),
+    (0x30000, 0x3134A, CharClass::Alphabetic),
+];
@@ -83,6 +83,10 @@ macro_rules! str_group_by {
         impl<'a, P> std::iter::FusedIterator for $name<'a, P>
         where P: FnMut(char, char) -> bool,
         { }
+
+        unsafe impl<'a, P> crate::GroupIterator for $name<'a, P>
+        where P: FnMut(char, char) -> bool,
+        { }
     }
 }
 
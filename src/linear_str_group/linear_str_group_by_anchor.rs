@@ -0,0 +1,280 @@
+use std::mem;
+use super::{str_as_ptr, str_as_mut_ptr, str_from_raw_parts, str_from_raw_parts_mut};
+
+macro_rules! str_group_by_anchor {
+    (struct $name:ident, $elem:ty, $as_ptr:ident, $as_str:ident) => {
+        impl<'a, P> $name<'a, P> {
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                self.inner
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            #[inline]
+            pub fn remainder_len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+
+        impl<'a, P> std::iter::Iterator for $name<'a, P>
+        where P: FnMut(char, char) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.char_indices();
+                let (_, anchor) = iter.next()?;
+
+                for (bi, bc) in iter {
+                    if !(self.predicate)(anchor, bc) {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, bi) };
+                        let right = unsafe { $as_str(ptr.add(bi), len - bi) };
+
+                        self.inner = right;
+                        return Some(left);
+                    }
+                }
+
+                Some(mem::take(&mut self.inner))
+            }
+        }
+
+        impl<'a, P> std::iter::DoubleEndedIterator for $name<'a, P>
+        where P: FnMut(char, char) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.char_indices().rev();
+                let (mut ai, anchor) = iter.next()?;
+
+                for (bi, bc) in iter {
+                    if !(self.predicate)(bc, anchor) {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, ai) };
+                        let right = unsafe { $as_str(ptr.add(ai), len - ai) };
+
+                        self.inner = left;
+                        return Some(right);
+                    }
+                    ai = bi;
+                }
+
+                Some(mem::take(&mut self.inner))
+            }
+        }
+
+        impl<'a, P> std::iter::FusedIterator for $name<'a, P>
+        where P: FnMut(char, char) -> bool,
+        { }
+
+        unsafe impl<'a, P> crate::GroupIterator for $name<'a, P>
+        where P: FnMut(char, char) -> bool,
+        { }
+    }
+}
+
+macro_rules! str_group_by_key_anchor {
+    (struct $name:ident, $elem:ty, $as_ptr:ident, $as_str:ident) => {
+        impl<'a, F, P> $name<'a, F, P> {
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                self.inner
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            #[inline]
+            pub fn remainder_len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+
+        impl<'a, F, K, P> std::iter::Iterator for $name<'a, F, P>
+        where F: FnMut(char) -> K,
+              P: FnMut(&K, &K) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.char_indices();
+                let (_, ac) = iter.next()?;
+                let anchor = (self.func)(ac);
+
+                for (bi, bc) in iter {
+                    if !(self.predicate)(&anchor, &(self.func)(bc)) {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, bi) };
+                        let right = unsafe { $as_str(ptr.add(bi), len - bi) };
+
+                        self.inner = right;
+                        return Some(left);
+                    }
+                }
+
+                Some(mem::take(&mut self.inner))
+            }
+        }
+
+        impl<'a, F, K, P> std::iter::DoubleEndedIterator for $name<'a, F, P>
+        where F: FnMut(char) -> K,
+              P: FnMut(&K, &K) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.char_indices().rev();
+                let (mut ai, ac) = iter.next()?;
+                let anchor = (self.func)(ac);
+
+                for (bi, bc) in iter {
+                    if !(self.predicate)(&(self.func)(bc), &anchor) {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, ai) };
+                        let right = unsafe { $as_str(ptr.add(ai), len - ai) };
+
+                        self.inner = left;
+                        return Some(right);
+                    }
+                    ai = bi;
+                }
+
+                Some(mem::take(&mut self.inner))
+            }
+        }
+
+        impl<'a, F, K, P> std::iter::FusedIterator for $name<'a, F, P>
+        where F: FnMut(char) -> K,
+              P: FnMut(&K, &K) -> bool,
+        { }
+
+        unsafe impl<'a, F, K, P> crate::GroupIterator for $name<'a, F, P>
+        where F: FnMut(char) -> K,
+              P: FnMut(&K, &K) -> bool,
+        { }
+    }
+}
+
+/// An iterator that will return non-overlapping groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give the first `char` of the current group and the `char`
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the `char` preceding the group
+/// and the last `char` of the current group.
+pub struct LinearStrGroupByAnchor<'a, P> {
+    inner: &'a str,
+    predicate: P,
+}
+
+impl<'a, P> LinearStrGroupByAnchor<'a, P>
+where P: FnMut(char, char) -> bool,
+{
+    pub fn new(string: &'a str, predicate: P) -> Self {
+        Self { inner: string, predicate }
+    }
+}
+
+str_group_by_anchor!{ struct LinearStrGroupByAnchor, &'a str, str_as_ptr, str_from_raw_parts }
+
+/// An iterator that will return non-overlapping *mutable* groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give the first `char` of the current group and the `char`
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the `char` preceding the group
+/// and the last `char` of the current group.
+pub struct LinearStrGroupByAnchorMut<'a, P> {
+    inner: &'a mut str,
+    predicate: P,
+}
+
+impl<'a, P> LinearStrGroupByAnchorMut<'a, P>
+where P: FnMut(char, char) -> bool,
+{
+    pub fn new(string: &'a mut str, predicate: P) -> Self {
+        Self { inner: string, predicate }
+    }
+}
+
+impl<'a, P> LinearStrGroupByAnchorMut<'a, P> {
+    #[inline]
+    pub fn as_str_mut(&mut self) -> &mut str {
+        self.inner
+    }
+}
+
+str_group_by_anchor!{ struct LinearStrGroupByAnchorMut, &'a mut str, str_as_mut_ptr, str_from_raw_parts_mut }
+
+/// An iterator that will return non-overlapping groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give a `char` to the given function, producing a key, and will give
+/// the key of the first `char` of the current group and the key of the `char`
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the key of the `char` preceding the group
+/// and the key of the last `char` of the current group.
+pub struct LinearStrGroupByKeyAnchor<'a, F, P> {
+    inner: &'a str,
+    func: F,
+    predicate: P,
+}
+
+impl<'a, F, K, P> LinearStrGroupByKeyAnchor<'a, F, P>
+where F: FnMut(char) -> K,
+      P: FnMut(&K, &K) -> bool,
+{
+    pub fn new(string: &'a str, func: F, predicate: P) -> Self {
+        Self { inner: string, func, predicate }
+    }
+}
+
+str_group_by_key_anchor!{ struct LinearStrGroupByKeyAnchor, &'a str, str_as_ptr, str_from_raw_parts }
+
+/// An iterator that will return non-overlapping *mutable* groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give a `char` to the given function, producing a key, and will give
+/// the key of the first `char` of the current group and the key of the `char`
+/// following the group to the predicate function, in this order.
+/// When iterating from the back it will give the key of the `char` preceding the group
+/// and the key of the last `char` of the current group.
+pub struct LinearStrGroupByKeyAnchorMut<'a, F, P> {
+    inner: &'a mut str,
+    func: F,
+    predicate: P,
+}
+
+impl<'a, F, K, P> LinearStrGroupByKeyAnchorMut<'a, F, P>
+where F: FnMut(char) -> K,
+      P: FnMut(&K, &K) -> bool,
+{
+    pub fn new(string: &'a mut str, func: F, predicate: P) -> Self {
+        Self { inner: string, func, predicate }
+    }
+}
+
+impl<'a, F, P> LinearStrGroupByKeyAnchorMut<'a, F, P> {
+    #[inline]
+    pub fn as_str_mut(&mut self) -> &mut str {
+        self.inner
+    }
+}
+
+str_group_by_key_anchor!{ struct LinearStrGroupByKeyAnchorMut, &'a mut str, str_as_mut_ptr, str_from_raw_parts_mut }
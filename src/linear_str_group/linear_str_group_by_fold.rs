@@ -0,0 +1,155 @@
+use std::mem;
+use super::{str_as_ptr, str_as_mut_ptr, str_from_raw_parts, str_from_raw_parts_mut};
+
+macro_rules! str_group_by_fold {
+    (struct $name:ident, $elem:ty, $as_ptr:ident, $as_str:ident) => {
+        impl<'a, S, F> $name<'a, S, F> {
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                self.inner
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            #[inline]
+            pub fn remainder_len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+
+        impl<'a, S, F> std::iter::Iterator for $name<'a, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, char) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.char_indices();
+                let (_, first) = iter.next()?;
+
+                let mut state = self.init.clone();
+                (self.step)(&mut state, first);
+
+                for (bi, bc) in iter {
+                    if !(self.step)(&mut state, bc) {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, bi) };
+                        let right = unsafe { $as_str(ptr.add(bi), len - bi) };
+
+                        self.inner = right;
+                        return Some(left);
+                    }
+                }
+
+                Some(mem::take(&mut self.inner))
+            }
+        }
+
+        impl<'a, S, F> std::iter::DoubleEndedIterator for $name<'a, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, char) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.char_indices().rev();
+                let (mut ai, last) = iter.next()?;
+
+                let mut state = self.init.clone();
+                (self.step)(&mut state, last);
+
+                for (bi, bc) in iter {
+                    if !(self.step)(&mut state, bc) {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, ai) };
+                        let right = unsafe { $as_str(ptr.add(ai), len - ai) };
+
+                        self.inner = left;
+                        return Some(right);
+                    }
+                    ai = bi;
+                }
+
+                Some(mem::take(&mut self.inner))
+            }
+        }
+
+        impl<'a, S, F> std::iter::FusedIterator for $name<'a, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, char) -> bool,
+        { }
+
+        unsafe impl<'a, S, F> crate::GroupIterator for $name<'a, S, F>
+        where S: Clone,
+              F: FnMut(&mut S, char) -> bool,
+        { }
+    }
+}
+
+/// An iterator that will return non-overlapping groups in the `str`
+/// using *linear/sequential search*.
+///
+/// Every group starts with a clone of the initial state, the step function is called
+/// with the state and each `char`, the first `char` of a group always joins it and
+/// the following `char`s join it as long as the step function returns `true`.
+///
+/// When iterating from the back the groups are built starting from their last `char`,
+/// giving the `char`s to the step function in reverse order, therefore the groups
+/// are not necessarily the same as the ones returned from the front.
+pub struct LinearStrGroupByFold<'a, S, F> {
+    inner: &'a str,
+    init: S,
+    step: F,
+}
+
+impl<'a, S, F> LinearStrGroupByFold<'a, S, F>
+where S: Clone,
+      F: FnMut(&mut S, char) -> bool,
+{
+    pub fn new(string: &'a str, init: S, step: F) -> Self {
+        Self { inner: string, init, step }
+    }
+}
+
+str_group_by_fold!{ struct LinearStrGroupByFold, &'a str, str_as_ptr, str_from_raw_parts }
+
+/// An iterator that will return non-overlapping *mutable* groups in the `str`
+/// using *linear/sequential search*.
+///
+/// Every group starts with a clone of the initial state, the step function is called
+/// with the state and each `char`, the first `char` of a group always joins it and
+/// the following `char`s join it as long as the step function returns `true`.
+///
+/// When iterating from the back the groups are built starting from their last `char`,
+/// giving the `char`s to the step function in reverse order, therefore the groups
+/// are not necessarily the same as the ones returned from the front.
+pub struct LinearStrGroupByFoldMut<'a, S, F> {
+    inner: &'a mut str,
+    init: S,
+    step: F,
+}
+
+impl<'a, S, F> LinearStrGroupByFoldMut<'a, S, F>
+where S: Clone,
+      F: FnMut(&mut S, char) -> bool,
+{
+    pub fn new(string: &'a mut str, init: S, step: F) -> Self {
+        Self { inner: string, init, step }
+    }
+}
+
+impl<'a, S, F> LinearStrGroupByFoldMut<'a, S, F> {
+    #[inline]
+    pub fn as_str_mut(&mut self) -> &mut str {
+        self.inner
+    }
+}
+
+str_group_by_fold!{ struct LinearStrGroupByFoldMut, &'a mut str, str_as_mut_ptr, str_from_raw_parts_mut }
@@ -0,0 +1,152 @@
+use std::mem;
+use unicode_segmentation::UnicodeSegmentation;
+use super::{str_as_ptr, str_as_mut_ptr, str_from_raw_parts, str_from_raw_parts_mut};
+
+macro_rules! str_group_by_grapheme {
+    (struct $name:ident, $elem:ty, $as_ptr:ident, $as_str:ident) => {
+        impl<'a, P> $name<'a, P> {
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                self.inner
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            #[inline]
+            pub fn remainder_len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+
+        impl<'a, P> std::iter::Iterator for $name<'a, P>
+        where P: FnMut(&str, &str) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.grapheme_indices(true);
+                let (_, mut ag) = iter.next()?;
+
+                let mut split = None;
+                for (bi, bg) in iter {
+                    if !(self.predicate)(ag, bg) {
+                        split = Some(bi);
+                        break;
+                    }
+                    ag = bg;
+                }
+
+                match split {
+                    Some(bi) => {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, bi) };
+                        let right = unsafe { $as_str(ptr.add(bi), len - bi) };
+
+                        self.inner = right;
+                        Some(left)
+                    },
+                    None => Some(mem::take(&mut self.inner)),
+                }
+            }
+
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, P> std::iter::DoubleEndedIterator for $name<'a, P>
+        where P: FnMut(&str, &str) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let mut iter = self.inner.grapheme_indices(true).rev();
+                let (mut ai, mut ag) = iter.next()?;
+
+                let mut split = None;
+                for (bi, bg) in iter {
+                    if !(self.predicate)(bg, ag) {
+                        split = Some(ai);
+                        break;
+                    }
+                    ai = bi;
+                    ag = bg;
+                }
+
+                match split {
+                    Some(ai) => {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, ai) };
+                        let right = unsafe { $as_str(ptr.add(ai), len - ai) };
+
+                        self.inner = left;
+                        Some(right)
+                    },
+                    None => Some(mem::take(&mut self.inner)),
+                }
+            }
+        }
+
+        impl<'a, P> std::iter::FusedIterator for $name<'a, P>
+        where P: FnMut(&str, &str) -> bool,
+        { }
+
+        unsafe impl<'a, P> crate::GroupIterator for $name<'a, P>
+        where P: FnMut(&str, &str) -> bool,
+        { }
+    }
+}
+
+/// An iterator that will return non-overlapping groups in the `str`
+/// using *linear/sequential search* over its extended grapheme clusters.
+///
+/// It will give two contiguous grapheme clusters to the predicate function,
+/// therefore a group never splits a grapheme cluster apart.
+pub struct LinearStrGroupByGrapheme<'a, P> {
+    inner: &'a str,
+    predicate: P,
+}
+
+impl<'a, P> LinearStrGroupByGrapheme<'a, P>
+where P: FnMut(&str, &str) -> bool,
+{
+    pub fn new(string: &'a str, predicate: P) -> Self {
+        Self { inner: string, predicate }
+    }
+}
+
+str_group_by_grapheme!{ struct LinearStrGroupByGrapheme, &'a str, str_as_ptr, str_from_raw_parts }
+
+/// An iterator that will return non-overlapping *mutable* groups in the `str`
+/// using *linear/sequential search* over its extended grapheme clusters.
+///
+/// It will give two contiguous grapheme clusters to the predicate function,
+/// therefore a group never splits a grapheme cluster apart.
+pub struct LinearStrGroupByGraphemeMut<'a, P> {
+    inner: &'a mut str,
+    predicate: P,
+}
+
+impl<'a, P> LinearStrGroupByGraphemeMut<'a, P>
+where P: FnMut(&str, &str) -> bool,
+{
+    pub fn new(string: &'a mut str, predicate: P) -> Self {
+        Self { inner: string, predicate }
+    }
+}
+
+impl<'a, P> LinearStrGroupByGraphemeMut<'a, P> {
+    #[inline]
+    pub fn as_str_mut(&mut self) -> &mut str {
+        self.inner
+    }
+}
+
+str_group_by_grapheme!{ struct LinearStrGroupByGraphemeMut, &'a mut str, str_as_mut_ptr, str_from_raw_parts_mut }
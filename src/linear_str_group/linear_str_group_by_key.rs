@@ -86,6 +86,11 @@ macro_rules! str_group_by_key {
         where F: FnMut(char) -> K,
               K: PartialEq,
         { }
+
+        unsafe impl<'a, F, K> crate::GroupIterator for $name<'a, F>
+        where F: FnMut(char) -> K,
+              K: PartialEq,
+        { }
     }
 }
 
@@ -105,6 +110,12 @@ impl<'a, F> LinearStrGroupByKey<'a, F> {
     }
 }
 
+impl<'a, F> LinearStrGroupByKey<'a, F> {
+    pub(crate) fn func_mut(&mut self) -> &mut F {
+        &mut self.func
+    }
+}
+
 str_group_by_key!{ struct LinearStrGroupByKey, &'a str, str_as_ptr, str_from_raw_parts }
 
 /// An iterator that will return non-overlapping *mutable* groups in the `str`
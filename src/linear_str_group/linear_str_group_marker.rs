@@ -0,0 +1,202 @@
+use std::mem;
+use super::{str_as_ptr, str_as_mut_ptr, str_from_raw_parts, str_from_raw_parts_mut};
+
+macro_rules! str_group_marker {
+    (struct $name:ident, $elem:ty, $as_ptr:ident, $as_str:ident, $marker:ident, $front_index:ident, $back_index:ident) => {
+        impl<'a, P> $name<'a, P> {
+            #[inline]
+            pub fn as_str(&self) -> &str {
+                self.inner
+            }
+
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            #[inline]
+            pub fn remainder_len(&self) -> usize {
+                self.inner.len()
+            }
+        }
+
+        impl<'a, P> std::iter::Iterator for $name<'a, P>
+        where P: FnMut(char) -> bool,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.inner.is_empty() { return None }
+
+                match $front_index(&self.inner, &mut self.$marker) {
+                    Some(bi) => {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, bi) };
+                        let right = unsafe { $as_str(ptr.add(bi), len - bi) };
+
+                        self.inner = right;
+                        Some(left)
+                    },
+                    None => Some(mem::take(&mut self.inner)),
+                }
+            }
+
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, P> std::iter::DoubleEndedIterator for $name<'a, P>
+        where P: FnMut(char) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.inner.is_empty() { return None }
+
+                match $back_index(&self.inner, &mut self.$marker) {
+                    Some(ai) => {
+                        let len = self.inner.len();
+                        let ptr = $as_ptr(self.inner);
+
+                        let left = unsafe { $as_str(ptr, ai) };
+                        let right = unsafe { $as_str(ptr.add(ai), len - ai) };
+
+                        self.inner = left;
+                        Some(right)
+                    },
+                    None => Some(mem::take(&mut self.inner)),
+                }
+            }
+        }
+
+        impl<'a, P> std::iter::FusedIterator for $name<'a, P>
+        where P: FnMut(char) -> bool,
+        { }
+
+        unsafe impl<'a, P> crate::GroupIterator for $name<'a, P>
+        where P: FnMut(char) -> bool,
+        { }
+    }
+}
+
+// returns the byte index of the first head that is not the first `char`
+fn starting_front_index<P: FnMut(char) -> bool>(string: &str, is_head: &mut P) -> Option<usize> {
+    string.char_indices().skip(1).find(|&(_, c)| is_head(c)).map(|(i, _)| i)
+}
+
+// returns the byte index of the last head that is not the first `char`
+fn starting_back_index<P: FnMut(char) -> bool>(string: &str, is_head: &mut P) -> Option<usize> {
+    string.char_indices().rev().take_while(|&(i, _)| i != 0).find(|&(_, c)| is_head(c)).map(|(i, _)| i)
+}
+
+// returns the byte index following the first tail that is not the last `char`
+fn ending_front_index<P: FnMut(char) -> bool>(string: &str, is_tail: &mut P) -> Option<usize> {
+    let mut iter = string.char_indices().map(|(i, c)| (i + c.len_utf8(), c));
+    iter.find(|&(end, c)| end != string.len() && is_tail(c)).map(|(end, _)| end)
+}
+
+// returns the byte index following the last tail that is not the last `char`
+fn ending_back_index<P: FnMut(char) -> bool>(string: &str, is_tail: &mut P) -> Option<usize> {
+    string.char_indices().rev().skip(1).find(|&(_, c)| is_tail(c)).map(|(i, c)| i + c.len_utf8())
+}
+
+/// An iterator that will return non-overlapping groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give each `char` to the predicate function, a new group starts
+/// at every `char` for which it returns `true`.
+pub struct LinearStrGroupStartingWith<'a, P> {
+    inner: &'a str,
+    is_head: P,
+}
+
+impl<'a, P> LinearStrGroupStartingWith<'a, P>
+where P: FnMut(char) -> bool,
+{
+    pub fn new(string: &'a str, is_head: P) -> Self {
+        Self { inner: string, is_head }
+    }
+}
+
+str_group_marker!{ struct LinearStrGroupStartingWith, &'a str, str_as_ptr, str_from_raw_parts,
+                   is_head, starting_front_index, starting_back_index }
+
+/// An iterator that will return non-overlapping *mutable* groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give each `char` to the predicate function, a new group starts
+/// at every `char` for which it returns `true`.
+pub struct LinearStrGroupStartingWithMut<'a, P> {
+    inner: &'a mut str,
+    is_head: P,
+}
+
+impl<'a, P> LinearStrGroupStartingWithMut<'a, P>
+where P: FnMut(char) -> bool,
+{
+    pub fn new(string: &'a mut str, is_head: P) -> Self {
+        Self { inner: string, is_head }
+    }
+}
+
+impl<'a, P> LinearStrGroupStartingWithMut<'a, P> {
+    #[inline]
+    pub fn as_str_mut(&mut self) -> &mut str {
+        self.inner
+    }
+}
+
+str_group_marker!{ struct LinearStrGroupStartingWithMut, &'a mut str, str_as_mut_ptr, str_from_raw_parts_mut,
+                   is_head, starting_front_index, starting_back_index }
+
+/// An iterator that will return non-overlapping groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give each `char` to the predicate function, a group ends
+/// at every `char` for which it returns `true`.
+pub struct LinearStrGroupEndingWith<'a, P> {
+    inner: &'a str,
+    is_tail: P,
+}
+
+impl<'a, P> LinearStrGroupEndingWith<'a, P>
+where P: FnMut(char) -> bool,
+{
+    pub fn new(string: &'a str, is_tail: P) -> Self {
+        Self { inner: string, is_tail }
+    }
+}
+
+str_group_marker!{ struct LinearStrGroupEndingWith, &'a str, str_as_ptr, str_from_raw_parts,
+                   is_tail, ending_front_index, ending_back_index }
+
+/// An iterator that will return non-overlapping *mutable* groups in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give each `char` to the predicate function, a group ends
+/// at every `char` for which it returns `true`.
+pub struct LinearStrGroupEndingWithMut<'a, P> {
+    inner: &'a mut str,
+    is_tail: P,
+}
+
+impl<'a, P> LinearStrGroupEndingWithMut<'a, P>
+where P: FnMut(char) -> bool,
+{
+    pub fn new(string: &'a mut str, is_tail: P) -> Self {
+        Self { inner: string, is_tail }
+    }
+}
+
+impl<'a, P> LinearStrGroupEndingWithMut<'a, P> {
+    #[inline]
+    pub fn as_str_mut(&mut self) -> &mut str {
+        self.inner
+    }
+}
+
+str_group_marker!{ struct LinearStrGroupEndingWithMut, &'a mut str, str_as_mut_ptr, str_from_raw_parts_mut,
+                   is_tail, ending_front_index, ending_back_index }
@@ -0,0 +1,135 @@
+use std::iter::FusedIterator;
+use std::fmt;
+
+/// Returns the line without its `"\n"` or `"\r\n"` line terminator.
+pub(crate) fn trim_newline(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Returns the byte index following the end of the line starting at `start`,
+/// including the line terminator.
+fn line_end(string: &str, start: usize) -> usize {
+    match string.as_bytes()[start..].iter().position(|&b| b == b'\n') {
+        Some(i) => start + i + 1,
+        None => string.len(),
+    }
+}
+
+/// Returns the byte index of the start of the line ending at `end`,
+/// the line terminator being part of the line.
+fn line_start(string: &str, end: usize) -> usize {
+    match string.as_bytes()[..end - 1].iter().rposition(|&b| b == b'\n') {
+        Some(i) => i + 1,
+        None => 0,
+    }
+}
+
+/// An iterator that will return non-overlapping groups of whole lines in the `str`
+/// using *linear/sequential search*.
+///
+/// It will give every line, without its `"\n"` or `"\r\n"` line terminator,
+/// to the given function, producing a key and comparing the keys to determine groups.
+/// The groups contain the line terminators of their lines.
+pub struct LinesGroupByKey<'a, F> {
+    inner: &'a str,
+    func: F,
+}
+
+impl<'a, F, K> LinesGroupByKey<'a, F>
+where F: FnMut(&str) -> K,
+      K: PartialEq,
+{
+    pub fn new(string: &'a str, func: F) -> Self {
+        LinesGroupByKey { inner: string, func }
+    }
+}
+
+impl<'a, F> LinesGroupByKey<'a, F> {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.inner
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn remainder_len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, F, K> Iterator for LinesGroupByKey<'a, F>
+where F: FnMut(&str) -> K,
+      K: PartialEq,
+{
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() { return None }
+
+        let string = self.inner;
+        let mut end = line_end(string, 0);
+        let key = (self.func)(trim_newline(&string[..end]));
+
+        while end != string.len() {
+            let next_end = line_end(string, end);
+            if (self.func)(trim_newline(&string[end..next_end])) != key { break }
+            end = next_end;
+        }
+
+        let (left, right) = string.split_at(end);
+        self.inner = right;
+        Some(left)
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, F, K> DoubleEndedIterator for LinesGroupByKey<'a, F>
+where F: FnMut(&str) -> K,
+      K: PartialEq,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() { return None }
+
+        let string = self.inner;
+        let mut start = line_start(string, string.len());
+        let key = (self.func)(trim_newline(&string[start..]));
+
+        while start != 0 {
+            let prev_start = line_start(string, start);
+            if (self.func)(trim_newline(&string[prev_start..start])) != key { break }
+            start = prev_start;
+        }
+
+        let (left, right) = string.split_at(start);
+        self.inner = left;
+        Some(right)
+    }
+}
+
+impl<'a, F, K> FusedIterator for LinesGroupByKey<'a, F>
+where F: FnMut(&str) -> K,
+      K: PartialEq,
+{ }
+
+unsafe impl<'a, F, K> crate::GroupIterator for LinesGroupByKey<'a, F>
+where F: FnMut(&str) -> K,
+      K: PartialEq,
+{ }
+
+impl<'a, F> fmt::Debug for LinesGroupByKey<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LinesGroupByKey")
+            .field("remainder", &self.inner)
+            .finish()
+    }
+}
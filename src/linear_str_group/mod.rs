@@ -38,16 +38,48 @@ macro_rules! str_group_by_wrapped {
         }
 
         impl<'a> std::iter::FusedIterator for $name<'a> { }
+
+        unsafe impl<'a> crate::GroupIterator for $name<'a> { }
     }
 }
 
 mod linear_str_group;
 mod linear_str_group_by;
 mod linear_str_group_by_key;
+mod linear_str_group_by_anchor;
+mod linear_str_group_by_fold;
+mod linear_str_group_marker;
+mod str_tokens;
+mod char_class_tables;
+mod str_group_spans;
+mod lines_group_by_key;
+#[cfg(feature = "unicode-segmentation")]
+mod linear_str_group_by_grapheme;
 
 pub use self::linear_str_group::{LinearStrGroup, LinearStrGroupMut};
 pub use self::linear_str_group_by::{LinearStrGroupBy, LinearStrGroupByMut};
 pub use self::linear_str_group_by_key::{LinearStrGroupByKey, LinearStrGroupByKeyMut};
+pub use self::linear_str_group_by_anchor::{
+    LinearStrGroupByAnchor,
+    LinearStrGroupByAnchorMut,
+    LinearStrGroupByKeyAnchor,
+    LinearStrGroupByKeyAnchorMut,
+};
+pub use self::linear_str_group_by_fold::{LinearStrGroupByFold, LinearStrGroupByFoldMut};
+pub use self::linear_str_group_marker::{
+    LinearStrGroupStartingWith,
+    LinearStrGroupStartingWithMut,
+    LinearStrGroupEndingWith,
+    LinearStrGroupEndingWithMut,
+};
+pub use self::str_tokens::{StrTokens, CharClass};
+pub use self::str_group_spans::{StrGroupSpans, StrGroupSpan};
+pub use self::lines_group_by_key::LinesGroupByKey;
+
+#[cfg(feature = "std")]
+pub(crate) use self::lines_group_by_key::trim_newline;
+#[cfg(feature = "unicode-segmentation")]
+pub use self::linear_str_group_by_grapheme::{LinearStrGroupByGrapheme, LinearStrGroupByGraphemeMut};
 
 fn str_as_ptr(string: &str) -> *const u8 {
     string.as_bytes().as_ptr()
@@ -175,4 +207,308 @@ mod tests {
         assert_eq!(iter.next(), Some("abc"));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn str_anchor() {
+        let string = "abcdefgh";
+
+        let mut iter = LinearStrGroupByAnchor::new(string, |a, b| (b as u32) - (a as u32) < 3);
+
+        assert_eq!(iter.next(), Some("abc"));
+        assert_eq!(iter.next(), Some("def"));
+        assert_eq!(iter.next(), Some("gh"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_rev_anchor() {
+        let string = "abcdefgh";
+
+        let mut iter = LinearStrGroupByAnchor::new(string, |a, b| (b as u32) - (a as u32) < 3).rev();
+
+        assert_eq!(iter.next(), Some("fgh"));
+        assert_eq!(iter.next(), Some("cde"));
+        assert_eq!(iter.next(), Some("ab"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::double_ended_iterator_last)]
+    fn str_anchor_last_is_forward_last() {
+        let string = "abcdefgh";
+
+        let iter = LinearStrGroupByAnchor::new(string, |a, b| (b as u32) - (a as u32) < 3);
+        assert_eq!(iter.last(), Some("gh"));
+
+        let iter = LinearStrGroupByKeyAnchor::new(string, |c| c as u32, |a, b| b - a < 3);
+        assert_eq!(iter.last(), Some("gh"));
+    }
+
+    #[test]
+    fn str_mut_key_anchor() {
+        let mut string = String::from("aab饰饰c");
+
+        let mut iter = LinearStrGroupByKeyAnchorMut::new(&mut string, |c| c.len_utf8(), |a, b| a == b);
+
+        assert_eq!(iter.next().map(|s| &*s), Some("aab"));
+        assert_eq!(iter.next_back().map(|s| &*s), Some("c"));
+        assert_eq!(iter.next().map(|s| &*s), Some("饰饰"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_fold_bytes_len() {
+        let string = "aaa饰饰bb";
+
+        let mut iter = LinearStrGroupByFold::new(string, 0, |len, c| { *len += c.len_utf8(); *len <= 6 });
+
+        assert_eq!(iter.next(), Some("aaa饰"));
+        assert_eq!(iter.next(), Some("饰bb"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    #[allow(clippy::double_ended_iterator_last)]
+    fn str_fold_last_is_forward_last() {
+        let string = "ab饰";
+
+        // from the back the first group is "b饰"
+        let iter = LinearStrGroupByFold::new(string, 0, |len, c| { *len += c.len_utf8(); *len <= 4 });
+        assert_eq!(iter.last(), Some("饰"));
+    }
+
+    #[test]
+    fn str_rev_fold_bytes_len() {
+        let string = "aaa饰饰bb";
+
+        let mut iter = LinearStrGroupByFold::new(string, 0, |len, c| { *len += c.len_utf8(); *len <= 6 }).rev();
+
+        assert_eq!(iter.next(), Some("饰bb"));
+        assert_eq!(iter.next(), Some("aaa饰"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_starting_with() {
+        let string = ">seq1\nACGT\n>seq2\nTT饰\n";
+
+        let mut iter = LinearStrGroupStartingWith::new(string, |c| c == '>');
+
+        assert_eq!(iter.next(), Some(">seq1\nACGT\n"));
+        assert_eq!(iter.next_back(), Some(">seq2\nTT饰\n"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_rev_starting_with() {
+        let string = "ab>c>>d";
+
+        let mut iter = LinearStrGroupStartingWith::new(string, |c| c == '>').rev();
+
+        assert_eq!(iter.next(), Some(">d"));
+        assert_eq!(iter.next(), Some(">"));
+        assert_eq!(iter.next(), Some(">c"));
+        assert_eq!(iter.next(), Some("ab"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_ending_with() {
+        let string = "饰.b..c";
+
+        let mut iter = LinearStrGroupEndingWith::new(string, |c| c == '.');
+
+        assert_eq!(iter.next(), Some("饰."));
+        assert_eq!(iter.next(), Some("b."));
+        assert_eq!(iter.next(), Some("."));
+        assert_eq!(iter.next(), Some("c"));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_mut_rev_ending_with() {
+        let mut string = String::from("饰.b..c.");
+
+        let mut iter = LinearStrGroupEndingWithMut::new(&mut string, |c| c == '.').rev();
+
+        assert_eq!(iter.next().map(|s| &*s), Some("c."));
+        assert_eq!(iter.next().map(|s| &*s), Some("."));
+        assert_eq!(iter.next().map(|s| &*s), Some("b."));
+        assert_eq!(iter.next().map(|s| &*s), Some("饰."));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn grapheme_never_split() {
+        // a waving hand with a skin tone modifier is a single grapheme cluster
+        let string = "aa\u{1F44B}\u{1F3FD}\u{1F44B}b";
+
+        let iter = LinearStrGroupByGrapheme::new(string, |a, b| a.len() == b.len());
+        let groups: Vec<_> = iter.collect();
+        assert_eq!(groups, vec!["aa", "\u{1F44B}\u{1F3FD}", "\u{1F44B}", "b"]);
+
+        // the char based iterator splits the modifier apart
+        let iter = LinearStrGroupBy::new(string, |a: char, b: char| a.len_utf8() == b.len_utf8());
+        assert_eq!(iter.count(), 3);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn rev_grapheme_groups() {
+        let string = "o\u{308}o\u{308}ooa";
+
+        let iter = LinearStrGroupByGrapheme::new(string, |a, b| a == b).rev();
+        let groups: Vec<_> = iter.collect();
+        assert_eq!(groups, vec!["a", "oo", "o\u{308}o\u{308}"]);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn mut_grapheme_groups() {
+        let mut string = String::from("e\u{301}e\u{301}xyz");
+
+        let mut iter = LinearStrGroupByGraphemeMut::new(&mut string, |a, b| a.len() == b.len());
+        let group = iter.next().unwrap();
+        assert_eq!(&*group, "e\u{301}e\u{301}");
+        assert_eq!(iter.as_str_mut(), "xyz");
+        iter.next().unwrap().make_ascii_uppercase();
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(string, "e\u{301}e\u{301}XYZ");
+    }
+
+    #[test]
+    fn str_tokens_rev() {
+        let string = "café 12€!";
+
+        let iter = StrTokens::new(string, CharClass::of).rev();
+        let tokens: Vec<_> = iter.collect();
+        assert_eq!(tokens, vec![
+            (CharClass::Punctuation, "!"),
+            (CharClass::Symbol, "€"),
+            (CharClass::Numeric, "12"),
+            (CharClass::Whitespace, " "),
+            (CharClass::Alphabetic, "café"),
+        ]);
+    }
+
+    #[test]
+    fn str_tokens_unicode_classes() {
+        let string = "«oui»—©2022\u{301}";
+
+        let tokens: Vec<_> = StrTokens::new(string, CharClass::of).collect();
+        assert_eq!(tokens, vec![
+            (CharClass::Punctuation, "«"),
+            (CharClass::Alphabetic, "oui"),
+            (CharClass::Punctuation, "»—"),
+            (CharClass::Symbol, "©"),
+            (CharClass::Numeric, "2022"),
+            (CharClass::Other, "\u{301}"),
+        ]);
+
+        assert_eq!(CharClass::of('Ⅳ'), CharClass::Alphabetic);
+        assert_eq!(CharClass::of('٣'), CharClass::Numeric);
+        assert_eq!(CharClass::of('\u{85}'), CharClass::Whitespace);
+        assert_eq!(CharClass::of('\u{7f}'), CharClass::Control);
+        // unassigned in Unicode 14.0.0
+        assert_eq!(CharClass::of('\u{1FA75}'), CharClass::Other);
+    }
+
+    #[test]
+    fn str_tokens_classifier() {
+        let string = "snake_case-id\t";
+
+        let classifier = |c: char| match c {
+            '_' => CharClass::Alphabetic,
+            c => CharClass::of(c),
+        };
+
+        let mut iter = StrTokens::new(string, classifier);
+        assert_eq!(iter.next(), Some((CharClass::Alphabetic, "snake_case")));
+        assert_eq!(iter.next_back(), Some((CharClass::Whitespace, "\t")));
+        assert_eq!(iter.as_str(), "-id");
+        assert_eq!(iter.next(), Some((CharClass::Punctuation, "-")));
+        assert_eq!(iter.next(), Some((CharClass::Alphabetic, "id")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn str_group_spans() {
+        let string = "aa\nbéé\n\ncc";
+
+        let iter = LinearStrGroup::new(string);
+        let spans: Vec<_> = StrGroupSpans::new(string, iter).map(|s| (s.bytes, s.chars, s.line, s.column, s.text)).collect();
+        assert_eq!(spans, vec![
+            (0..2, 0..2, 0, 0, "aa"),
+            (2..3, 2..3, 0, 2, "\n"),
+            (3..4, 3..4, 1, 0, "b"),
+            (4..8, 4..6, 1, 1, "éé"),
+            (8..10, 6..8, 1, 3, "\n\n"),
+            (10..12, 8..10, 3, 0, "cc"),
+        ]);
+
+        let iter = LinearStrGroup::new(string);
+        let mut rev: Vec<_> = StrGroupSpans::new(string, iter).rev().map(|s| (s.bytes, s.chars, s.line, s.column, s.text)).collect();
+        rev.reverse();
+        assert_eq!(rev, spans);
+    }
+
+    #[test]
+    fn str_group_spans_both_ends() {
+        let string = "ab cd\nef gh";
+
+        let iter = LinearStrGroupBy::new(string, |a: char, b: char| a.is_whitespace() == b.is_whitespace());
+        let mut iter = StrGroupSpans::new(string, iter);
+
+        let span = iter.next_back().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (9..11, 9..11, 1, 3, "gh"));
+
+        let span = iter.next().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (0..2, 0..2, 0, 0, "ab"));
+
+        let span = iter.next_back().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (8..9, 8..9, 1, 2, " "));
+
+        let span = iter.next_back().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (6..8, 6..8, 1, 0, "ef"));
+
+        let span = iter.next_back().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (5..6, 5..6, 0, 5, "\n"));
+
+        let span = iter.next().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (2..3, 2..3, 0, 2, " "));
+
+        let span = iter.next_back().unwrap();
+        assert_eq!((span.bytes, span.chars, span.line, span.column, span.text), (3..5, 3..5, 0, 3, "cd"));
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn lines_group_by_key() {
+        let string = "a 1\na 2\r\nb 3\n\nc 4\nc 5";
+
+        let key = |line: &str| line.chars().next();
+        let groups: Vec<_> = LinesGroupByKey::new(string, key).collect();
+        assert_eq!(groups, vec!["a 1\na 2\r\n", "b 3\n", "\n", "c 4\nc 5"]);
+
+        let mut rev: Vec<_> = LinesGroupByKey::new(string, key).rev().collect();
+        rev.reverse();
+        assert_eq!(rev, groups);
+    }
+
+    #[test]
+    fn lines_group_by_key_both_ends() {
+        let string = "é\né\nà\nü\nü\n";
+
+        let mut iter = LinesGroupByKey::new(string, |line: &str| line.to_owned());
+        assert_eq!(iter.next_back(), Some("ü\nü\n"));
+        assert_eq!(iter.next(), Some("é\né\n"));
+        assert_eq!(iter.as_str(), "à\n");
+        assert_eq!(iter.next_back(), Some("à\n"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
 }
@@ -0,0 +1,164 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::fmt;
+
+/// A `str` group along with its position in the original `str`.
+///
+/// The `line` and the `column` of the first `char` of the group are zero-based,
+/// a line ends with a `'\n'` and the column is counted in `char`s.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StrGroupSpan<'a> {
+    /// The byte range of the group in the original `str`.
+    pub bytes: Range<usize>,
+    /// The `char` range of the group in the original `str`.
+    pub chars: Range<usize>,
+    /// The line of the first `char` of the group.
+    pub line: usize,
+    /// The column of the first `char` of the group.
+    pub column: usize,
+    /// The group itself.
+    pub text: &'a str,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cursor {
+    byte: usize,
+    char: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Cursor {
+    fn advance(&mut self, text: &str) {
+        self.byte += text.len();
+        for c in text.chars() {
+            self.char += 1;
+            if c == '\n' {
+                self.line += 1;
+                self.column = 0;
+            } else {
+                self.column += 1;
+            }
+        }
+    }
+}
+
+/// An iterator that wraps a `str` group iterator and returns the groups
+/// along with their byte and `char` ranges, their line and their column.
+///
+/// The positions are tracked incrementally, the front of the iterator only reads
+/// the returned groups and the back of the iterator reads the remainder once,
+/// the first time a group is returned from the back, to find the end position,
+/// then only reads the returned groups and the start of the line of the groups
+/// that span several lines.
+pub struct StrGroupSpans<'a, I> {
+    string: &'a str,
+    iter: I,
+    front: Cursor,
+    back: Option<Cursor>,
+}
+
+impl<'a, I> StrGroupSpans<'a, I>
+where I: Iterator<Item = &'a str>,
+{
+    /// Creates a new iterator from the `str` and an iterator
+    /// that returns contiguous groups of this `str`.
+    pub fn new(string: &'a str, iter: I) -> Self {
+        let front = Cursor { byte: 0, char: 0, line: 0, column: 0 };
+        StrGroupSpans { string, iter, front, back: None }
+    }
+
+    fn byte_offset(&self, group: &str) -> usize {
+        group.as_ptr() as usize - self.string.as_ptr() as usize
+    }
+}
+
+impl<'a, I> Iterator for StrGroupSpans<'a, I>
+where I: Iterator<Item = &'a str>,
+{
+    type Item = StrGroupSpan<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.iter.next()?;
+        debug_assert_eq!(self.byte_offset(text), self.front.byte);
+
+        let start = self.front;
+        self.front.advance(text);
+
+        Some(StrGroupSpan {
+            bytes: start.byte..self.front.byte,
+            chars: start.char..self.front.char,
+            line: start.line,
+            column: start.column,
+            text,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, I> DoubleEndedIterator for StrGroupSpans<'a, I>
+where I: DoubleEndedIterator<Item = &'a str>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let text = self.iter.next_back()?;
+        let start_byte = self.byte_offset(text);
+        let end_byte = start_byte + text.len();
+
+        let front = self.front;
+        let string = self.string;
+        let end = self.back.get_or_insert_with(|| {
+            let mut end = front;
+            end.advance(&string[front.byte..end_byte]);
+            end
+        });
+
+        let mut chars = 0;
+        let mut lines = 0;
+        for c in text.chars() {
+            chars += 1;
+            if c == '\n' { lines += 1 }
+        }
+
+        let start_char = end.char - chars;
+        let line = end.line - lines;
+        let column = if lines == 0 {
+            end.column - chars
+        } else {
+            // the start of the line is either after the front cursor or on its line
+            let before = &string[front.byte..start_byte];
+            match before.rfind('\n') {
+                Some(i) => before[i + 1..].chars().count(),
+                None => front.column + before.chars().count(),
+            }
+        };
+
+        *end = Cursor { byte: start_byte, char: start_char, line, column };
+
+        Some(StrGroupSpan {
+            bytes: start_byte..end_byte,
+            chars: start_char..start_char + chars,
+            line,
+            column,
+            text,
+        })
+    }
+}
+
+impl<'a, I> FusedIterator for StrGroupSpans<'a, I>
+where I: FusedIterator<Item = &'a str>,
+{ }
+
+impl<'a, I: fmt::Debug> fmt::Debug for StrGroupSpans<'a, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrGroupSpans")
+            .field("iter", &self.iter)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+use std::fmt;
+use super::LinearStrGroupByKey;
+use super::char_class_tables::CHAR_CLASS_TABLE;
+
+/// The class of a `char`, used to split a `str` into tokens.
+///
+/// The classes follow the Unicode 14.0.0 Character Database, whatever the version
+/// of Unicode used by the `char` methods of the standard library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharClass {
+    /// A `char` with the `Alphabetic` property, e.g. `a`, `é` or `字`.
+    Alphabetic,
+    /// A `char` of a number general category (`N*`), e.g. `7`, `½` or `٣`.
+    Numeric,
+    /// A `char` with the `White_Space` property, e.g. a space or a newline.
+    Whitespace,
+    /// A `char` of a punctuation general category (`P*`), e.g. `,`, `«` or `。`.
+    Punctuation,
+    /// A `char` of a symbol general category (`S*`), e.g. `+`, `€` or `©`.
+    Symbol,
+    /// A `char` of the control general category (`Cc`) that is not a whitespace.
+    Control,
+    /// Any other `char`, e.g. a combining mark, a format or an unassigned `char`.
+    Other,
+}
+
+impl CharClass {
+    /// Returns the class of the given `char`.
+    ///
+    /// A `char` that matches several classes is `Alphabetic` first, then `Numeric`,
+    /// then `Whitespace`, e.g. the `Ⅳ` roman numeral is `Alphabetic`.
+    pub fn of(c: char) -> CharClass {
+        let c = c as u32;
+        let result = CHAR_CLASS_TABLE.binary_search_by(|&(start, end, _)| {
+            if end < c { Ordering::Less } else if start > c { Ordering::Greater } else { Ordering::Equal }
+        });
+
+        match result {
+            Ok(i) => CHAR_CLASS_TABLE[i].2,
+            Err(_) => CharClass::Other,
+        }
+    }
+}
+
+/// An iterator that will return the runs of `char`s of the same class in the `str`
+/// along with their class, using a [`LinearStrGroupByKey`].
+///
+/// [`LinearStrGroupByKey`]: struct.LinearStrGroupByKey.html
+pub struct StrTokens<'a, F> {
+    inner: LinearStrGroupByKey<'a, F>,
+}
+
+impl<'a, F> StrTokens<'a, F>
+where F: FnMut(char) -> CharClass,
+{
+    pub fn new(string: &'a str, classifier: F) -> Self {
+        StrTokens { inner: LinearStrGroupByKey::new(string, classifier) }
+    }
+
+    fn classify(&mut self, token: &str) -> CharClass {
+        // a token returned by a LinearStrGroupByKey is never empty
+        let c = token.chars().next().unwrap();
+        (self.inner.func_mut())(c)
+    }
+}
+
+impl<'a, F> StrTokens<'a, F> {
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.inner.as_str()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn remainder_len(&self) -> usize {
+        self.inner.remainder_len()
+    }
+}
+
+impl<'a, F> Iterator for StrTokens<'a, F>
+where F: FnMut(char) -> CharClass,
+{
+    type Item = (CharClass, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let token = self.inner.next()?;
+        Some((self.classify(token), token))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a, F> DoubleEndedIterator for StrTokens<'a, F>
+where F: FnMut(char) -> CharClass,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let token = self.inner.next_back()?;
+        Some((self.classify(token), token))
+    }
+}
+
+impl<'a, F> FusedIterator for StrTokens<'a, F>
+where F: FnMut(char) -> CharClass,
+{ }
+
+impl<'a, F> fmt::Debug for StrTokens<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StrTokens")
+            .field("remainder", &self.as_str())
+            .finish()
+    }
+}
@@ -0,0 +1,80 @@
+mod record_slice;
+
+pub use self::record_slice::{RecordSlice, RecordSliceError, RecordGroupBy};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SearchMode;
+
+    // 32 bytes records with an 8 bytes big-endian key at offset 0
+    fn records(keys: &[u64]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for (i, key) in keys.iter().enumerate() {
+            let mut record = [0u8; 32];
+            record[..8].copy_from_slice(&key.to_be_bytes());
+            record[8..16].copy_from_slice(&(i as u64).to_le_bytes());
+            bytes.extend_from_slice(&record);
+        }
+        bytes
+    }
+
+    #[test]
+    fn checked_constructor() {
+        let bytes = records(&[1, 2, 3]);
+
+        assert_eq!(RecordSlice::new(&bytes, 0, 0..0), Err(RecordSliceError::ZeroRecordLen));
+        assert_eq!(RecordSlice::new(&bytes, 32, 30..34), Err(RecordSliceError::KeyOutOfRecord { key: 30..34, record_len: 32 }));
+        assert_eq!(RecordSlice::new(&bytes[1..], 32, 0..8), Err(RecordSliceError::UnalignedLen { len: 95, record_len: 32 }));
+
+        let slice = RecordSlice::new(&bytes, 32, 0..8).unwrap();
+        assert_eq!(slice.len(), 3);
+        assert_eq!(slice.key(1), Some(&2u64.to_be_bytes()[..]));
+        assert_eq!(slice.key(3), None);
+        assert_eq!(slice.records().count(), 3);
+    }
+
+    #[test]
+    fn all_modes_agree() {
+        let keys: Vec<u64> = (0..500).map(|i| i * i / 97 + 250).collect();
+        let bytes = records(&keys);
+        let slice = RecordSlice::new(&bytes, 32, 0..8).unwrap();
+
+        let linear: Vec<_> = slice.linear_group_by_key().collect();
+        let binary: Vec<_> = slice.binary_group_by_key().collect();
+        let exponential: Vec<_> = slice.exponential_group_by_key().collect();
+
+        let mut expected = keys.clone();
+        expected.dedup();
+        assert_eq!(linear.len(), expected.len());
+        assert_eq!(linear, binary);
+        assert_eq!(linear, exponential);
+
+        for mode in [SearchMode::Linear, SearchMode::Binary, SearchMode::Exponential] {
+            let mut rev: Vec<_> = slice.group_by_key(mode).rev().collect();
+            rev.reverse();
+            assert_eq!(rev, linear);
+        }
+
+        for (group, key) in linear.iter().zip(expected) {
+            assert_eq!(group.len() % 32, 0);
+            assert!(group.chunks(32).all(|r| r[..8] == key.to_be_bytes()));
+        }
+    }
+
+    #[test]
+    fn both_ends() {
+        let bytes = records(&[1, 1, 2, 3, 3, 3]);
+        let slice = RecordSlice::new(&bytes, 32, 0..8).unwrap();
+
+        let mut iter = slice.exponential_group_by_key();
+        assert_eq!(iter.next_back(), Some(&bytes[96..]));
+        assert_eq!(iter.next(), Some(&bytes[..64]));
+        assert_eq!(iter.remainder().len(), 1);
+        assert_eq!(iter.next_back(), Some(&bytes[64..96]));
+        assert_eq!(iter.next(), None);
+
+        let empty = RecordSlice::new(&[], 32, 0..8).unwrap();
+        assert_eq!(empty.binary_group_by_key().next(), None);
+    }
+}
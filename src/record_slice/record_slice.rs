@@ -0,0 +1,258 @@
+use std::iter::FusedIterator;
+use std::ops::Range;
+use std::slice::ChunksExact;
+use std::fmt;
+use crate::{run_len, SearchMode};
+
+/// The error returned by [`RecordSlice::new`] when the bytes
+/// cannot be viewed as a slice of records.
+///
+/// [`RecordSlice::new`]: struct.RecordSlice.html#method.new
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordSliceError {
+    /// The record length is zero.
+    ZeroRecordLen,
+    /// The key byte range is not contained in the records.
+    KeyOutOfRecord { key: Range<usize>, record_len: usize },
+    /// The length of the bytes is not a multiple of the record length.
+    UnalignedLen { len: usize, record_len: usize },
+}
+
+impl fmt::Display for RecordSliceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecordSliceError::ZeroRecordLen => {
+                f.write_str("record length cannot be zero")
+            },
+            RecordSliceError::KeyOutOfRecord { key, record_len } => {
+                write!(f, "key range {:?} is out of the {} bytes records", key, record_len)
+            },
+            RecordSliceError::UnalignedLen { len, record_len } => {
+                write!(f, "length {} is not a multiple of the record length {}", len, record_len)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordSliceError { }
+
+/// A view over bytes made of fixed-size records, each record
+/// having its key at the same byte range.
+///
+/// The keys are compared byte by byte, like `memcmp`, therefore the records
+/// must be sorted by their key bytes, for example by big-endian integers,
+/// to be grouped using *binary* or *exponential search*.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RecordSlice<'a> {
+    bytes: &'a [u8],
+    record_len: usize,
+    key: Range<usize>,
+}
+
+impl<'a> RecordSlice<'a> {
+    /// Creates a view over the bytes made of `record_len` bytes records,
+    /// the key of each record being at the `key` byte range of the record.
+    ///
+    /// ```rust
+    /// use slice_group_by::{RecordSlice, RecordSliceError};
+    ///
+    /// let bytes = [0u8; 12];
+    ///
+    /// assert!(RecordSlice::new(&bytes, 4, 0..2).is_ok());
+    /// assert_eq!(RecordSlice::new(&bytes, 5, 0..2), Err(RecordSliceError::UnalignedLen { len: 12, record_len: 5 }));
+    /// assert!(RecordSlice::new(&bytes, 4, 2..6).is_err());
+    /// ```
+    pub fn new(bytes: &'a [u8], record_len: usize, key: Range<usize>) -> Result<Self, RecordSliceError> {
+        if record_len == 0 {
+            return Err(RecordSliceError::ZeroRecordLen)
+        }
+        if key.start > key.end || key.end > record_len {
+            return Err(RecordSliceError::KeyOutOfRecord { key, record_len })
+        }
+        // usize::is_multiple_of requires Rust 1.87
+        #[allow(clippy::manual_is_multiple_of)]
+        if bytes.len() % record_len != 0 {
+            return Err(RecordSliceError::UnalignedLen { len: bytes.len(), record_len })
+        }
+        Ok(RecordSlice { bytes, record_len, key })
+    }
+
+    /// Returns the underlying bytes.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the length of the records in bytes.
+    pub fn record_len(&self) -> usize {
+        self.record_len
+    }
+
+    /// Returns the byte range of the key in the records.
+    pub fn key_range(&self) -> Range<usize> {
+        self.key.clone()
+    }
+
+    /// Returns the number of records.
+    pub fn len(&self) -> usize {
+        self.bytes.len() / self.record_len
+    }
+
+    /// Returns `true` if there is no record.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the record at the given index, or `None` if out of bounds.
+    pub fn record(&self, index: usize) -> Option<&'a [u8]> {
+        let start = index.checked_mul(self.record_len)?;
+        self.bytes.get(start..start.checked_add(self.record_len)?)
+    }
+
+    /// Returns the key of the record at the given index, or `None` if out of bounds.
+    pub fn key(&self, index: usize) -> Option<&'a [u8]> {
+        self.record(index).map(|record| &record[self.key.clone()])
+    }
+
+    /// Returns an iterator over the records.
+    pub fn records(&self) -> ChunksExact<'a, u8> {
+        self.bytes.chunks_exact(self.record_len)
+    }
+
+    /// Returns an iterator on runs of whole records that have the same key
+    /// using the given search method.
+    pub fn group_by_key(&self, mode: SearchMode) -> RecordGroupBy<'a> {
+        RecordGroupBy::new(self.clone(), mode)
+    }
+
+    /// Returns an iterator on runs of whole records that have the same key
+    /// using the *linear search* method, the records do not need to be sorted.
+    pub fn linear_group_by_key(&self) -> RecordGroupBy<'a> {
+        self.group_by_key(SearchMode::Linear)
+    }
+
+    /// Returns an iterator on runs of whole records that have the same key
+    /// using the *binary search* method, the records must be sorted by key.
+    ///
+    /// ```rust
+    /// use slice_group_by::RecordSlice;
+    ///
+    /// // 4 bytes records with a 2 bytes big-endian key at offset 0
+    /// let bytes = [0, 1, 7, 7,  0, 1, 8, 8,  0, 2, 9, 9];
+    /// let records = RecordSlice::new(&bytes, 4, 0..2).unwrap();
+    ///
+    /// let mut iter = records.binary_group_by_key();
+    ///
+    /// assert_eq!(iter.next(), Some(&bytes[..8]));
+    /// assert_eq!(iter.next(), Some(&bytes[8..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn binary_group_by_key(&self) -> RecordGroupBy<'a> {
+        self.group_by_key(SearchMode::Binary)
+    }
+
+    /// Returns an iterator on runs of whole records that have the same key
+    /// using the *exponential search* method, the records must be sorted by key.
+    pub fn exponential_group_by_key(&self) -> RecordGroupBy<'a> {
+        self.group_by_key(SearchMode::Exponential)
+    }
+
+    fn split_at(&self, mid: usize) -> (RecordSlice<'a>, RecordSlice<'a>) {
+        let (left, right) = self.bytes.split_at(mid * self.record_len);
+        let left = RecordSlice { bytes: left, record_len: self.record_len, key: self.key.clone() };
+        let right = RecordSlice { bytes: right, record_len: self.record_len, key: self.key.clone() };
+        (left, right)
+    }
+
+    // the index must be in bounds
+    fn key_unchecked(&self, index: usize) -> &'a [u8] {
+        let start = index * self.record_len;
+        &self.bytes[start + self.key.start..start + self.key.end]
+    }
+}
+
+impl fmt::Debug for RecordSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RecordSlice")
+            .field("len", &self.len())
+            .field("record_len", &self.record_len)
+            .field("key", &self.key)
+            .finish()
+    }
+}
+
+/// An iterator that will return non-overlapping runs of whole records
+/// of a [`RecordSlice`] that have the same key.
+///
+/// [`RecordSlice`]: struct.RecordSlice.html
+pub struct RecordGroupBy<'a> {
+    records: RecordSlice<'a>,
+    mode: SearchMode,
+}
+
+impl<'a> RecordGroupBy<'a> {
+    pub fn new(records: RecordSlice<'a>, mode: SearchMode) -> Self {
+        RecordGroupBy { records, mode }
+    }
+
+    /// Returns the remainder of the original records that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> RecordSlice<'a> {
+        self.records.clone()
+    }
+}
+
+impl<'a> Iterator for RecordGroupBy<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.records.is_empty() { return None }
+
+        let records = &self.records;
+        let first = records.key_unchecked(0);
+        let len = run_len(records.len(), self.mode, |i| records.key_unchecked(i) == first);
+
+        let (head, tail) = self.records.split_at(len);
+        self.records = tail;
+        Some(head.bytes)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.records.len();
+        if len == 0 { (0, Some(0)) } else { (1, Some(len)) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a> DoubleEndedIterator for RecordGroupBy<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.records.is_empty() { return None }
+
+        let records = &self.records;
+        let last_index = records.len() - 1;
+        let last = records.key_unchecked(last_index);
+        let len = run_len(records.len(), self.mode, |i| records.key_unchecked(last_index - i) == last);
+
+        let (head, tail) = self.records.split_at(records.len() - len);
+        self.records = head;
+        Some(tail.bytes)
+    }
+}
+
+impl<'a> FusedIterator for RecordGroupBy<'a> { }
+
+impl<'a> fmt::Debug for RecordGroupBy<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RecordGroupBy")
+            .field("records", &self.records)
+            .field("mode", &self.mode)
+            .finish()
+    }
+}
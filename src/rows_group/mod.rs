@@ -0,0 +1,97 @@
+mod rows_group;
+
+pub use self::rows_group::{
+    rows_group_by_key,
+    rows_group_by_key_mut,
+    binary_rows_group_by_key,
+    binary_rows_group_by_key_mut,
+    exponential_rows_group_by_key,
+    exponential_rows_group_by_key_mut,
+    RowsGroupByKey,
+    RowsGroupByKeyMut,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_modes_agree() {
+        let row_len = 4;
+        let mut matrix = Vec::new();
+        for i in 0..300 {
+            matrix.extend_from_slice(&[i / 11, i, i * 2, i * 3]);
+        }
+
+        let linear: Vec<_> = rows_group_by_key(&matrix, row_len, |row| row[0]).collect();
+        let binary: Vec<_> = binary_rows_group_by_key(&matrix, row_len, |row| row[0]).collect();
+        let exponential: Vec<_> = exponential_rows_group_by_key(&matrix, row_len, |row| row[0]).collect();
+
+        assert_eq!(linear.len(), 28);
+        assert_eq!(linear, binary);
+        assert_eq!(linear, exponential);
+        assert!(linear.iter().all(|g| g.len() % row_len == 0));
+
+        let mut rev: Vec<_> = exponential_rows_group_by_key(&matrix, row_len, |row| row[0]).rev().collect();
+        rev.reverse();
+        assert_eq!(rev, linear);
+    }
+
+    #[test]
+    fn key_over_whole_row() {
+        let matrix = &[
+            1, 2,
+            1, 2,
+            2, 1,
+            1, 2,
+        ];
+
+        let mut iter = rows_group_by_key(matrix, 2, |row| row.iter().sum::<i32>());
+        assert_eq!(iter.next(), Some(&matrix[..]));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = rows_group_by_key(matrix, 2, |row| row.to_vec());
+        assert_eq!(iter.next_back(), Some(&[1, 2][..]));
+        assert_eq!(iter.next(), Some(&[1, 2, 1, 2][..]));
+        assert_eq!(iter.remainder(), &[2, 1]);
+        assert_eq!(iter.next_back(), Some(&[2, 1][..]));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn rows_mut() {
+        let matrix = &mut [
+            1, 0,
+            1, 0,
+            2, 0,
+            3, 0,
+            3, 0,
+        ];
+
+        for group in binary_rows_group_by_key_mut(matrix, 2, |row| row[0]) {
+            let rows = group.len() / 2;
+            for row in group.chunks_exact_mut(2) {
+                row[1] = rows;
+            }
+        }
+
+        assert_eq!(matrix, &[1, 2, 1, 2, 2, 1, 3, 2, 3, 2]);
+
+        let mut iter = exponential_rows_group_by_key_mut(matrix, 2, |row| row[0]);
+        assert_eq!(iter.next_back(), Some(&mut [3, 2, 3, 2][..]));
+        assert_eq!(iter.into_remainder(), &mut [1, 2, 1, 2, 2, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_row_len() {
+        let _ = rows_group_by_key(&[1, 2, 3], 0, |row| row[0]);
+    }
+
+    #[test]
+    fn empty() {
+        let matrix: &[i32] = &[];
+        assert_eq!(rows_group_by_key(matrix, 3, |row| row[0]).next(), None);
+        assert_eq!(rows_group_by_key_mut(&mut [0; 0], 3, |row: &[i32]| row[0]).next_back(), None);
+    }
+}
@@ -0,0 +1,295 @@
+use std::{fmt, mem};
+use crate::{run_len, SearchMode};
+
+macro_rules! rows_group_by_key {
+    (struct $name:ident, $elem:ty, $split:ident) => {
+        impl<'a, T: 'a, F> $name<'a, T, F> {
+            fn rows(&self) -> usize {
+                self.slice.len() / self.row_len
+            }
+
+            fn row(&self, index: usize) -> &[T] {
+                let start = index * self.row_len;
+                &self.slice[start..start + self.row_len]
+            }
+        }
+
+        impl<'a, T: 'a, F, K> std::iter::Iterator for $name<'a, T, F>
+        where F: Fn(&[T]) -> K,
+              K: PartialEq,
+        {
+            type Item = $elem;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let rows = self.rows();
+                if rows == 0 { return None }
+
+                let first = (self.func)(self.row(0));
+                let len = run_len(rows, self.mode, |i| (self.func)(self.row(i)) == first);
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split(len * self.row_len);
+                self.slice = tail;
+                Some(head)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let rows = self.rows();
+                if rows == 0 { (0, Some(0)) } else { (1, Some(rows)) }
+            }
+
+            #[inline]
+            fn last(mut self) -> Option<Self::Item> {
+                self.next_back()
+            }
+        }
+
+        impl<'a, T: 'a, F, K> std::iter::DoubleEndedIterator for $name<'a, T, F>
+        where F: Fn(&[T]) -> K,
+              K: PartialEq,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let rows = self.rows();
+                if rows == 0 { return None }
+
+                let last_index = rows - 1;
+                let last = (self.func)(self.row(last_index));
+                let len = run_len(rows, self.mode, |i| (self.func)(self.row(last_index - i)) == last);
+
+                let slice = mem::take(&mut self.slice);
+                let (head, tail) = slice.$split((rows - len) * self.row_len);
+                self.slice = head;
+                Some(tail)
+            }
+        }
+
+        impl<'a, T: 'a, F, K> std::iter::FusedIterator for $name<'a, T, F>
+        where F: Fn(&[T]) -> K,
+              K: PartialEq,
+        { }
+
+        impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for $name<'a, T, F> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("slice", &self.slice)
+                    .field("row_len", &self.row_len)
+                    .field("mode", &self.mode)
+                    .finish()
+            }
+        }
+    }
+}
+
+/// An iterator that will return non-overlapping groups of whole rows in a slice
+/// that stores rows of `row_len` elements one after the other.
+///
+/// It will give every row to the given function, producing a key and comparing
+/// the keys to determine groups. The rows must be sorted, or at least grouped, by key
+/// to use the *binary* or *exponential search* method.
+///
+/// # Panics
+///
+/// Panics if `row_len` is 0 and, in debug builds, if the slice length is not a multiple
+/// of `row_len`. In release builds, the elements of a trailing incomplete row are cut
+/// from the slice on creation, they are neither returned nor part of the remainder.
+pub struct RowsGroupByKey<'a, T: 'a, F> {
+    slice: &'a [T],
+    row_len: usize,
+    mode: SearchMode,
+    func: F,
+}
+
+impl<'a, T: 'a, F, K> RowsGroupByKey<'a, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    /// Creates an iterator on the groups of rows of `row_len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_len` is 0 and, in debug builds, if the slice length
+    /// is not a multiple of `row_len`.
+    // usize::is_multiple_of requires Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn new(slice: &'a [T], row_len: usize, mode: SearchMode, func: F) -> Self {
+        assert!(row_len != 0, "row_len cannot be zero");
+        debug_assert!(slice.len() % row_len == 0, "slice length must be a multiple of row_len");
+        let slice = &slice[..slice.len() - slice.len() % row_len];
+        RowsGroupByKey { slice, row_len, mode, func }
+    }
+}
+
+impl<'a, T: 'a, F> RowsGroupByKey<'a, T, F> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn remainder(&self) -> &[T] {
+        self.slice
+    }
+}
+
+rows_group_by_key!{ struct RowsGroupByKey, &'a [T], split_at }
+
+/// An iterator that will return non-overlapping *mutable* groups of whole rows in a slice
+/// that stores rows of `row_len` elements one after the other.
+///
+/// It will give every row to the given function, producing a key and comparing
+/// the keys to determine groups. The rows must be sorted, or at least grouped, by key
+/// to use the *binary* or *exponential search* method.
+///
+/// # Panics
+///
+/// Panics if `row_len` is 0 and, in debug builds, if the slice length is not a multiple
+/// of `row_len`. In release builds, the elements of a trailing incomplete row are cut
+/// from the slice on creation, they are neither returned nor part of the remainder.
+pub struct RowsGroupByKeyMut<'a, T: 'a, F> {
+    slice: &'a mut [T],
+    row_len: usize,
+    mode: SearchMode,
+    func: F,
+}
+
+impl<'a, T: 'a, F, K> RowsGroupByKeyMut<'a, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    /// Creates an iterator on the *mutable* groups of rows of `row_len` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row_len` is 0 and, in debug builds, if the slice length
+    /// is not a multiple of `row_len`.
+    // usize::is_multiple_of requires Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    pub fn new(slice: &'a mut [T], row_len: usize, mode: SearchMode, func: F) -> Self {
+        assert!(row_len != 0, "row_len cannot be zero");
+        debug_assert!(slice.len() % row_len == 0, "slice length must be a multiple of row_len");
+        let len = slice.len() - slice.len() % row_len;
+        let slice = &mut slice[..len];
+        RowsGroupByKeyMut { slice, row_len, mode, func }
+    }
+}
+
+impl<'a, T: 'a, F> RowsGroupByKeyMut<'a, T, F> {
+    /// Returns the remainder of the original slice that is going to be
+    /// returned by the iterator.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+rows_group_by_key!{ struct RowsGroupByKeyMut, &'a mut [T], split_at_mut }
+
+/// Returns an iterator on the groups of whole rows of `row_len` elements of a flattened
+/// two dimensional slice, the rows of a group having the same key. It uses *linear search*
+/// to iterate over groups.
+///
+/// # Panics
+///
+/// Panics in the same cases as [`RowsGroupByKey`].
+///
+/// [`RowsGroupByKey`]: struct.RowsGroupByKey.html
+///
+/// ```rust
+/// use slice_group_by::rows_group_by_key;
+///
+/// let matrix = &[
+///     1, 10, 100,
+///     1, 20, 200,
+///     2, 30, 300,
+/// ];
+///
+/// let mut iter = rows_group_by_key(matrix, 3, |row| row[0]);
+///
+/// assert_eq!(iter.next(), Some(&[1, 10, 100, 1, 20, 200][..]));
+/// assert_eq!(iter.next(), Some(&[2, 30, 300][..]));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn rows_group_by_key<T, F, K>(slice: &[T], row_len: usize, func: F) -> RowsGroupByKey<'_, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    RowsGroupByKey::new(slice, row_len, SearchMode::Linear, func)
+}
+
+/// Returns an iterator on the *mutable* groups of whole rows of `row_len` elements of a flattened
+/// two dimensional slice, the rows of a group having the same key. It uses *linear search*
+/// to iterate over groups.
+///
+/// # Panics
+///
+/// Panics in the same cases as [`RowsGroupByKeyMut`].
+///
+/// [`RowsGroupByKeyMut`]: struct.RowsGroupByKeyMut.html
+pub fn rows_group_by_key_mut<T, F, K>(slice: &mut [T], row_len: usize, func: F) -> RowsGroupByKeyMut<'_, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    RowsGroupByKeyMut::new(slice, row_len, SearchMode::Linear, func)
+}
+
+/// Returns an iterator on the groups of whole rows of `row_len` elements of a flattened
+/// two dimensional slice sorted by key, the rows of a group having the same key.
+/// It uses *binary search* to iterate over groups.
+///
+/// # Panics
+///
+/// Panics in the same cases as [`RowsGroupByKey`].
+///
+/// [`RowsGroupByKey`]: struct.RowsGroupByKey.html
+pub fn binary_rows_group_by_key<T, F, K>(slice: &[T], row_len: usize, func: F) -> RowsGroupByKey<'_, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    RowsGroupByKey::new(slice, row_len, SearchMode::Binary, func)
+}
+
+/// Returns an iterator on the *mutable* groups of whole rows of `row_len` elements of a flattened
+/// two dimensional slice sorted by key, the rows of a group having the same key.
+/// It uses *binary search* to iterate over groups.
+///
+/// # Panics
+///
+/// Panics in the same cases as [`RowsGroupByKeyMut`].
+///
+/// [`RowsGroupByKeyMut`]: struct.RowsGroupByKeyMut.html
+pub fn binary_rows_group_by_key_mut<T, F, K>(slice: &mut [T], row_len: usize, func: F) -> RowsGroupByKeyMut<'_, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    RowsGroupByKeyMut::new(slice, row_len, SearchMode::Binary, func)
+}
+
+/// Returns an iterator on the groups of whole rows of `row_len` elements of a flattened
+/// two dimensional slice sorted by key, the rows of a group having the same key.
+/// It uses *exponential search* to iterate over groups.
+///
+/// # Panics
+///
+/// Panics in the same cases as [`RowsGroupByKey`].
+///
+/// [`RowsGroupByKey`]: struct.RowsGroupByKey.html
+pub fn exponential_rows_group_by_key<T, F, K>(slice: &[T], row_len: usize, func: F) -> RowsGroupByKey<'_, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    RowsGroupByKey::new(slice, row_len, SearchMode::Exponential, func)
+}
+
+/// Returns an iterator on the *mutable* groups of whole rows of `row_len` elements of a flattened
+/// two dimensional slice sorted by key, the rows of a group having the same key.
+/// It uses *exponential search* to iterate over groups.
+///
+/// # Panics
+///
+/// Panics in the same cases as [`RowsGroupByKeyMut`].
+///
+/// [`RowsGroupByKeyMut`]: struct.RowsGroupByKeyMut.html
+pub fn exponential_rows_group_by_key_mut<T, F, K>(slice: &mut [T], row_len: usize, func: F) -> RowsGroupByKeyMut<'_, T, F>
+where F: Fn(&[T]) -> K,
+      K: PartialEq,
+{
+    RowsGroupByKeyMut::new(slice, row_len, SearchMode::Exponential, func)
+}
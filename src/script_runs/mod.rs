@@ -0,0 +1,67 @@
+mod script_runs;
+mod tables;
+
+pub use self::script_runs::ScriptRuns;
+pub use self::tables::Script;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn script_of_chars() {
+        assert_eq!(Script::of('a'), Script::Latin);
+        assert_eq!(Script::of('я'), Script::Cyrillic);
+        assert_eq!(Script::of('漢'), Script::Han);
+        assert_eq!(Script::of('ع'), Script::Arabic);
+        assert_eq!(Script::of(' '), Script::Common);
+        assert_eq!(Script::of('\u{301}'), Script::Inherited);
+        assert_eq!(Script::of('\u{10FFFF}'), Script::Unknown);
+        assert_eq!(Script::OldItalic.name(), "Old_Italic");
+    }
+
+    #[test]
+    fn shared_chars_attach() {
+        let string = "(hello, мир!) 漢字";
+
+        let runs: Vec<_> = ScriptRuns::new(string).collect();
+        assert_eq!(runs, vec![
+            (Script::Latin, "(hello, "),
+            (Script::Cyrillic, "мир!) "),
+            (Script::Han, "漢字"),
+        ]);
+
+        let mut rev: Vec<_> = ScriptRuns::new(string).rev().collect();
+        rev.reverse();
+        assert_eq!(rev, runs);
+    }
+
+    #[test]
+    fn inherited_marks() {
+        // a combining acute accent follows the latin letter
+        let string = "e\u{301}д\u{301}";
+
+        let runs: Vec<_> = ScriptRuns::new(string).collect();
+        assert_eq!(runs, vec![(Script::Latin, "e\u{301}"), (Script::Cyrillic, "д\u{301}")]);
+    }
+
+    #[test]
+    fn double_ended() {
+        let string = "12 abc αβγ def";
+
+        let mut iter = ScriptRuns::new(string);
+        assert_eq!(iter.next_back(), Some((Script::Latin, "def")));
+        assert_eq!(iter.next(), Some((Script::Latin, "12 abc ")));
+        assert_eq!(iter.as_str(), "αβγ ");
+        assert_eq!(iter.next_back(), Some((Script::Greek, "αβγ ")));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn only_common() {
+        let runs: Vec<_> = ScriptRuns::new("123 !?").collect();
+        assert_eq!(runs, vec![(Script::Common, "123 !?")]);
+
+        assert_eq!(ScriptRuns::new("").next(), None);
+    }
+}
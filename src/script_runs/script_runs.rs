@@ -0,0 +1,131 @@
+use std::cmp::Ordering;
+use std::iter::FusedIterator;
+use std::{fmt, mem};
+use super::tables::{Script, DEFAULT_SCRIPT, SCRIPT_TABLE};
+
+impl Script {
+    /// Returns the script of the given `char`.
+    pub fn of(c: char) -> Script {
+        let c = c as u32;
+        let result = SCRIPT_TABLE.binary_search_by(|&(start, end, _)| {
+            if end < c { Ordering::Less } else if start > c { Ordering::Greater } else { Ordering::Equal }
+        });
+
+        match result {
+            Ok(i) => SCRIPT_TABLE[i].2,
+            Err(_) => DEFAULT_SCRIPT,
+        }
+    }
+
+    /// Returns `true` if the script is `Common` or `Inherited`,
+    /// these `char`s are used with multiple scripts.
+    pub fn is_shared(self) -> bool {
+        self == Script::Common || self == Script::Inherited
+    }
+}
+
+/// An iterator that will return the runs of the same [`Script`] in the `str`
+/// along with their script.
+///
+/// The `Common` and `Inherited` `char`s join the run of the preceding `char`,
+/// or the run of the following `char` at the start of the `str`, as described
+/// in [UAX #24]. A `str` made only of such `char`s is a single `Common` run.
+/// The paired brackets are not matched.
+///
+/// [`Script`]: enum.Script.html
+/// [UAX #24]: https://www.unicode.org/reports/tr24/
+pub struct ScriptRuns<'a> {
+    inner: &'a str,
+}
+
+impl<'a> ScriptRuns<'a> {
+    pub fn new(string: &'a str) -> Self {
+        ScriptRuns { inner: string }
+    }
+
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.inner
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    #[inline]
+    pub fn remainder_len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a> Iterator for ScriptRuns<'a> {
+    type Item = (Script, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() { return None }
+
+        let mut script = None;
+        for (i, c) in self.inner.char_indices() {
+            let s = Script::of(c);
+            if s.is_shared() { continue }
+
+            match script {
+                None => script = Some(s),
+                Some(script) if script != s => {
+                    let (left, right) = self.inner.split_at(i);
+                    self.inner = right;
+                    return Some((script, left));
+                },
+                Some(_) => (),
+            }
+        }
+
+        let script = script.unwrap_or(Script::Common);
+        Some((script, mem::take(&mut self.inner)))
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl<'a> DoubleEndedIterator for ScriptRuns<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.inner.is_empty() { return None }
+
+        // the run starts at the first char of its script that follows a char of another script
+        let mut script = None;
+        let mut start = 0;
+        for (i, c) in self.inner.char_indices().rev() {
+            let s = Script::of(c);
+            if s.is_shared() { continue }
+
+            match script {
+                None => script = Some(s),
+                Some(script) if script != s => {
+                    let (left, right) = self.inner.split_at(start);
+                    self.inner = left;
+                    return Some((script, right));
+                },
+                Some(_) => (),
+            }
+            start = i;
+        }
+
+        let script = script.unwrap_or(Script::Common);
+        Some((script, mem::take(&mut self.inner)))
+    }
+}
+
+impl<'a> FusedIterator for ScriptRuns<'a> { }
+
+impl<'a> fmt::Debug for ScriptRuns<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ScriptRuns")
+            .field("remainder", &self.inner)
+            .finish()
+    }
+}